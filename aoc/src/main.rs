@@ -0,0 +1,368 @@
+mod days;
+mod tools;
+
+use aoc_utils::answers::DEFAULT_ANSWERS_DIRECTORY;
+use aoc_utils::bench::{DEFAULT_BASELINE_PATH, Measurement};
+use aoc_utils::input::DEFAULT_INPUTS_DIRECTORY;
+use aoc_utils::{
+    AnswerStore, Answers, InputLoader, InputSource, ParseError, Part, Runnable, SolveError,
+    Verdict, bench,
+};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    /// Directory holding the puzzle inputs as `<year>/dayNN.txt`
+    #[arg(long, global = true, env = "AOC_INPUTS_DIR", default_value = DEFAULT_INPUTS_DIRECTORY)]
+    inputs_dir: PathBuf,
+
+    /// Directory holding the confirmed answers as `<year>/dayNN.toml`
+    #[arg(long, global = true, env = "AOC_ANSWERS_DIR", default_value = DEFAULT_ANSWERS_DIRECTORY)]
+    answers_dir: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day when no day is given
+    Run {
+        #[arg(short, long)]
+        day: Option<u8>,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file instead, or from stdin when given `-`
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Check the confirmed answers still come out of the solutions
+    #[command(group(ArgGroup::new("days").required(true)))]
+    Verify {
+        #[arg(short, long, group = "days")]
+        day: Option<u8>,
+        /// Verify every day that has confirmed answers
+        #[arg(long, group = "days")]
+        all: bool,
+    },
+    /// Time parsing and each part, and compare against a saved baseline
+    Bench(BenchOptions),
+    /// Day 11: count the stones after every blink
+    Day11(tools::Day11Options),
+    /// Day 17: take apart, debug and trace programs for the 3-bit computer
+    Day17(tools::Day17Options),
+    /// Day 18: find the way out of the memory space, optionally drawing it
+    Day18(tools::Day18Options),
+    /// Day 20: count the cheats by how much time they save
+    Day20(tools::Day20Options),
+}
+
+#[derive(Args)]
+struct BenchOptions {
+    #[arg(short, long)]
+    day: Option<u8>,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// How many times to solve each day, reporting the median
+    #[arg(short = 'n', long, default_value_t = 10)]
+    iterations: u32,
+    /// Also write the measurements as JSON to this file, away from anything printed on stdout
+    #[arg(long, value_name = "FILE")]
+    json: Option<PathBuf>,
+    /// File holding the baseline measurements
+    #[arg(long, default_value = DEFAULT_BASELINE_PATH)]
+    baseline: PathBuf,
+    /// Store these measurements as the new baseline
+    #[arg(long)]
+    save_baseline: bool,
+    /// How many percent slower than the baseline counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let loader = InputLoader::new(cli.inputs_dir);
+    let store = AnswerStore::new(cli.answers_dir);
+    let succeeded = match cli.command {
+        Command::Run { day, part, input } => run(
+            &select_days(day),
+            &select_parts(part),
+            &loader,
+            &store,
+            &InputSource::from_argument(input.as_deref()),
+        ),
+        Command::Verify { day, .. } => verify(&select_days(day), &loader, &store),
+        Command::Bench(options) => bench(
+            &select_days(options.day),
+            &select_parts(options.part),
+            &loader,
+            &options,
+        ),
+        Command::Day11(options) => tools::day11(&options, &loader),
+        Command::Day17(options) => tools::day17(&options, &loader),
+        Command::Day18(options) => tools::day18(&options, &loader),
+        Command::Day20(options) => tools::day20(&options, &loader),
+    };
+    if !succeeded {
+        std::process::exit(1);
+    }
+}
+
+fn select_days(day: Option<u8>) -> Vec<&'static dyn Runnable> {
+    match day {
+        None => days::DAYS.to_vec(),
+        Some(day) => match days::find(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("No solution for day {day}");
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+fn select_parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(2) => vec![Part::Two],
+        _ => Part::BOTH.to_vec(),
+    }
+}
+
+fn run(
+    solutions: &[&dyn Runnable],
+    parts: &[Part],
+    loader: &InputLoader,
+    store: &AnswerStore,
+    source: &InputSource,
+) -> bool {
+    let mut succeeded = true;
+    for solution in solutions {
+        let input = match loader.load(solution.year(), solution.day(), source) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {:02}: {error}", solution.day());
+                succeeded = false;
+                continue;
+            }
+        };
+        // Confirmed answers belong to the default input, so other inputs are never checked.
+        let mut answers = match source {
+            InputSource::Default => load_answers(store, *solution),
+            _ => None,
+        };
+        for part in parts {
+            let answer = match solution.solve(&input, *part) {
+                Ok(answer) => answer,
+                Err(SolveError::Parse(error)) => {
+                    report_parse_error(*solution, &error);
+                    succeeded = false;
+                    break;
+                }
+                Err(SolveError::NoAnswer(reason)) => {
+                    report_no_answer(*solution, *part, &reason);
+                    succeeded = false;
+                    continue;
+                }
+            };
+            let Some(answers) = &mut answers else {
+                println!("Day {:02} part {}: {}", solution.day(), part, answer);
+                continue;
+            };
+            let verdict = answers.check(*part, &answer);
+            println!(
+                "Day {:02} part {}: {} {}",
+                solution.day(),
+                part,
+                answer,
+                verdict
+            );
+            match verdict {
+                Verdict::Correct => {}
+                Verdict::Wrong { .. } => succeeded = false,
+                Verdict::Unknown => {
+                    if confirm("Save this answer as correct?") {
+                        answers.set(*part, answer);
+                        if let Err(error) = store.save(solution.year(), solution.day(), answers) {
+                            eprintln!("Day {:02}: {error}", solution.day());
+                            succeeded = false;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    succeeded
+}
+
+fn verify(solutions: &[&dyn Runnable], loader: &InputLoader, store: &AnswerStore) -> bool {
+    let mut regressions = Vec::new();
+    let mut succeeded = true;
+    for solution in solutions {
+        let Some(answers) = load_answers(store, *solution) else {
+            succeeded = false;
+            continue;
+        };
+        if answers.is_empty() {
+            println!("Day {:02}: no confirmed answers", solution.day());
+            continue;
+        }
+        let input = match loader.load(solution.year(), solution.day(), &InputSource::Default) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {:02}: {error}", solution.day());
+                succeeded = false;
+                continue;
+            }
+        };
+        for part in Part::BOTH {
+            if answers.get(part).is_none() {
+                continue;
+            }
+            let answer = match solution.solve(&input, part) {
+                Ok(answer) => answer,
+                Err(SolveError::Parse(error)) => {
+                    report_parse_error(*solution, &error);
+                    regressions.push(format!("day {:02}", solution.day()));
+                    break;
+                }
+                Err(SolveError::NoAnswer(reason)) => {
+                    report_no_answer(*solution, part, &reason);
+                    regressions.push(format!("day {:02} part {}", solution.day(), part));
+                    continue;
+                }
+            };
+            let verdict = answers.check(part, &answer);
+            println!(
+                "Day {:02} part {}: {} {}",
+                solution.day(),
+                part,
+                answer,
+                verdict
+            );
+            if verdict != Verdict::Correct {
+                regressions.push(format!("day {:02} part {}", solution.day(), part));
+            }
+        }
+    }
+    if regressions.is_empty() {
+        println!("No regressions");
+    } else {
+        println!("Regressions: {}", regressions.join(", "));
+        succeeded = false;
+    }
+    succeeded
+}
+
+fn bench(
+    solutions: &[&dyn Runnable],
+    parts: &[Part],
+    loader: &InputLoader,
+    options: &BenchOptions,
+) -> bool {
+    let mut succeeded = true;
+    let mut measurements = Vec::new();
+    for solution in solutions {
+        let input = match loader.load(solution.year(), solution.day(), &InputSource::Default) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {:02}: {error}", solution.day());
+                succeeded = false;
+                continue;
+            }
+        };
+        match bench::measure(*solution, &input, parts, options.iterations) {
+            Ok(measurement) => measurements.push(measurement),
+            Err(error) => {
+                report_parse_error(*solution, &error);
+                succeeded = false;
+            }
+        }
+    }
+
+    print_measurements(&measurements);
+    if let Some(path) = &options.json {
+        let json = serde_json::to_string_pretty(&measurements).unwrap();
+        if let Err(error) = std::fs::write(path, json + "\n") {
+            eprintln!("could not write {}: {error}", path.display());
+            succeeded = false;
+        }
+    }
+
+    if options.save_baseline {
+        if let Err(error) = bench::save_baseline(&options.baseline, &measurements) {
+            eprintln!("{error}");
+            return false;
+        }
+        eprintln!("Saved baseline to {}", options.baseline.display());
+        return succeeded;
+    }
+
+    let baseline = match bench::load_baseline(&options.baseline) {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("{error}");
+            return false;
+        }
+    };
+    let regressions = bench::find_regressions(&baseline, &measurements, options.threshold);
+    for regression in &regressions {
+        eprintln!("Regression: {regression}");
+    }
+    succeeded && regressions.is_empty()
+}
+
+fn print_measurements(measurements: &[Measurement]) {
+    let cell = |time: Option<f64>| time.map_or("-".to_string(), |time| format!("{time:.3}"));
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}",
+        "Day", "Parse (ms)", "Part 1 (ms)", "Part 2 (ms)"
+    );
+    for measurement in measurements {
+        println!(
+            "{:>3}  {:>12}  {:>12}  {:>12}",
+            format!("{:02}", measurement.day),
+            cell(Some(measurement.parse)),
+            cell(measurement.part1),
+            cell(measurement.part2)
+        );
+    }
+}
+
+fn report_parse_error(solution: &dyn Runnable, error: &ParseError) {
+    eprintln!("Day {:02}: could not parse the input", solution.day());
+    eprintln!("{}", error.snippet());
+}
+
+fn report_no_answer(solution: &dyn Runnable, part: Part, reason: &str) {
+    eprintln!("Day {:02} part {} failed: {reason}", solution.day(), part);
+}
+
+fn load_answers(store: &AnswerStore, solution: &dyn Runnable) -> Option<Answers> {
+    match store.load(solution.year(), solution.day()) {
+        Ok(answers) => Some(answers),
+        Err(error) => {
+            eprintln!("Day {:02}: {error}", solution.day());
+            None
+        }
+    }
+}
+
+/// Asks a yes/no question, answering no when nobody is at the terminal.
+fn confirm(question: &str) -> bool {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return false;
+    }
+    print!("{question} [y/N] ");
+    std::io::stdout().flush().ok();
+    let mut reply = String::new();
+    if stdin.lock().read_line(&mut reply).is_err() {
+        return false;
+    }
+    matches!(reply.trim(), "y" | "Y" | "yes")
+}
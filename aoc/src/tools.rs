@@ -0,0 +1,269 @@
+//! Extra commands for days whose puzzles are worth exploring beyond the two answers.
+
+use crate::report_parse_error;
+use aoc_utils::{InputLoader, InputSource, Solution};
+use clap::{Args, Subcommand};
+use day17::debugger::Debugger;
+use std::num::NonZeroU64;
+use std::path::PathBuf;
+
+/// Loads and parses a day's input for the given puzzle settings, reporting any problem on stderr.
+fn load_input<S: Solution>(
+    solution: &S,
+    loader: &InputLoader,
+    input: Option<&std::path::Path>,
+    params: &S::Params,
+) -> Option<S::Input> {
+    let source = InputSource::from_argument(input);
+    let text = match loader.load(S::YEAR, S::DAY, &source) {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("Day {:02}: {error}", S::DAY);
+            return None;
+        }
+    };
+    match S::parse_with(&text, params) {
+        Ok(parsed) => Some(parsed),
+        Err(error) => {
+            report_parse_error(solution, &error.locate(&text));
+            None
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct Day20Options {
+    /// Read the input from this file instead, or from stdin when given `-`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// The longest a cheat may last, in picoseconds
+    #[arg(long, default_value_t = 20)]
+    max_cheat: i64,
+    /// Only count cheats that save at least this many picoseconds, the real puzzle's threshold
+    /// when not given
+    #[arg(long)]
+    min_saving: Option<u32>,
+}
+
+/// Prints how many cheats save each amount of time, smallest saving first.
+pub fn day20(options: &Day20Options, loader: &InputLoader) -> bool {
+    let params = options
+        .min_saving
+        .map_or_else(day20::MinSaving::default, day20::MinSaving);
+    let Some(racetrack) = load_input(&day20::Day20, loader, options.input.as_deref(), &params)
+    else {
+        return false;
+    };
+    let min_saving = racetrack.min_saving;
+    let Some(savings) = day20::cheat_savings(&racetrack, options.max_cheat) else {
+        eprintln!("The end of the racetrack can't be reached");
+        return false;
+    };
+    let mut total = 0;
+    println!("{:>6}  {:>6}", "Saving", "Cheats");
+    for (saving, count) in savings.range(min_saving..) {
+        println!("{saving:>6}  {count:>6}");
+        total += count;
+    }
+    println!(
+        "{total} cheats of at most {} picoseconds save at least {min_saving}",
+        options.max_cheat
+    );
+    true
+}
+
+#[derive(Args)]
+pub struct Day11Options {
+    /// Read the input from this file instead, or from stdin when given `-`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// How many times to blink
+    #[arg(short, long, default_value_t = 75)]
+    blinks: usize,
+    /// Count the stones modulo this prime, for more blinks than fit in a u128
+    #[arg(short, long)]
+    modulo: Option<NonZeroU64>,
+}
+
+/// Prints the number of stones and of distinct values after every blink.
+pub fn day11(options: &Day11Options, loader: &InputLoader) -> bool {
+    let Some(values) = load_input(&day11::Day11, loader, options.input.as_deref(), &()) else {
+        return false;
+    };
+    let mut stones = day11::Stones::new(&values);
+    if let Some(modulus) = options.modulo {
+        stones = stones.modulo(modulus);
+    }
+    println!("{:>6}  {:>40}  {:>8}", "Blinks", "Stones", "Distinct");
+    while stones.blinks() < options.blinks {
+        let counted = stones.blink().and_then(|()| stones.total());
+        match counted {
+            Ok(total) => println!(
+                "{:>6}  {total:>40}  {:>8}",
+                stones.blinks(),
+                stones.distinct_values()
+            ),
+            Err(error) => {
+                eprintln!("{error}");
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[derive(Args)]
+pub struct Day17Options {
+    #[command(subcommand)]
+    command: Day17Command,
+}
+
+#[derive(Subcommand)]
+enum Day17Command {
+    /// Print the program as mnemonics
+    Disassemble {
+        /// Read the input from this file instead, or from stdin when given `-`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Explain each instruction as pseudo-code
+        #[arg(long)]
+        pseudo_code: bool,
+    },
+    /// Step through the program interactively
+    Debug {
+        /// Read the input from this file instead, or from stdin when given `-`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Run the program and write every step as CSV
+    Trace {
+        /// Read the input from this file instead, or from stdin when given `-`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Write the trace here instead of to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Turn mnemonics back into a `Program:` line
+    Assemble {
+        /// File with one instruction per line, or `-` for stdin
+        source: PathBuf,
+    },
+}
+
+pub fn day17(options: &Day17Options, loader: &InputLoader) -> bool {
+    match &options.command {
+        Day17Command::Disassemble { input, pseudo_code } => {
+            let Some(computer) = load_input(&day17::Day17, loader, input.as_deref(), &()) else {
+                return false;
+            };
+            println!(
+                "{}",
+                day17::assembly::disassemble(computer.program(), *pseudo_code)
+            );
+            true
+        }
+        Day17Command::Debug { input } => {
+            let Some(computer) = load_input(&day17::Day17, loader, input.as_deref(), &()) else {
+                return false;
+            };
+            println!(
+                "{}",
+                day17::assembly::disassemble(computer.program(), false)
+            );
+            let stdin = std::io::stdin();
+            report_io(Debugger::new(computer).run(stdin.lock(), std::io::stdout()))
+        }
+        Day17Command::Trace { input, output } => {
+            let Some(computer) = load_input(&day17::Day17, loader, input.as_deref(), &()) else {
+                return false;
+            };
+            let result = match output {
+                Some(path) => std::fs::File::create(path).and_then(|file| {
+                    day17::debugger::write_trace(computer, std::io::BufWriter::new(file))
+                }),
+                None => day17::debugger::write_trace(computer, std::io::stdout().lock()),
+            };
+            report_io(result)
+        }
+        Day17Command::Assemble { source } => {
+            let source = InputSource::from_argument(Some(source));
+            let text = match loader.load(day17::Day17::YEAR, day17::Day17::DAY, &source) {
+                Ok(text) => text,
+                Err(error) => {
+                    eprintln!("{error}");
+                    return false;
+                }
+            };
+            match day17::assembly::assemble(&text) {
+                Ok(program) => {
+                    let numbers: Vec<String> = program.iter().map(u8::to_string).collect();
+                    println!("Program: {}", numbers.join(","));
+                    true
+                }
+                Err(error) => {
+                    eprintln!("Could not assemble the program");
+                    eprintln!("{}", error.locate(&text).snippet());
+                    false
+                }
+            }
+        }
+    }
+}
+
+fn report_io(result: std::io::Result<()>) -> bool {
+    if let Err(error) = &result {
+        eprintln!("{error}");
+    }
+    result.is_ok()
+}
+
+#[derive(Args)]
+pub struct Day18Options {
+    /// Read the input from this file instead, or from stdin when given `-`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Width and height of the memory space, the real puzzle's when not given
+    #[arg(long)]
+    grid_size: Option<i64>,
+    /// How many bytes have fallen, the real puzzle's count when not given
+    #[arg(long)]
+    bytes: Option<usize>,
+    /// Draw the corrupted memory and the shortest path out
+    #[arg(long)]
+    render: bool,
+}
+
+/// Prints the shortest way out and the first byte that blocks it.
+pub fn day18(options: &Day18Options, loader: &InputLoader) -> bool {
+    let defaults = day18::MemoryParams::default();
+    let params = day18::MemoryParams {
+        grid_size: options.grid_size.unwrap_or(defaults.grid_size),
+        fallen_bytes: options.bytes.unwrap_or(defaults.fallen_bytes),
+    };
+    let Some(memory) = load_input(&day18::Day18, loader, options.input.as_deref(), &params) else {
+        return false;
+    };
+
+    if options.render {
+        println!("{}", memory.render());
+    }
+    match day18::find_shortest_path(&memory.fallen_grid()) {
+        Some((length, _)) => println!(
+            "After {} bytes the exit is {length} steps away",
+            memory.fallen_bytes
+        ),
+        None => println!(
+            "After {} bytes the exit can't be reached",
+            memory.fallen_bytes
+        ),
+    }
+    match day18::find_first_blocking_byte(&memory.byte_positions, memory.grid_size) {
+        Some(byte) => println!(
+            "The byte at index {} ({},{}) is the first to block the exit",
+            byte.index, byte.position.x, byte.position.y
+        ),
+        None => println!("The exit stays reachable"),
+    }
+    true
+}
@@ -1,10 +1,10 @@
 #!/usr/bin/python3
 import sys
 import os
-import shutil
 import cookie
 
-UTILS_SRC_DIRECTORY = "../utils/src"
+TEMPLATE_LIB = "../utils/template/lib.rs"
+DAYS_REGISTRY = "../aoc/src/days.rs"
 
 day = sys.argv[1]
 
@@ -15,11 +15,24 @@ if os.path.isdir(dir_path):
 os.mkdir(dir_path)
 os.chdir(dir_path)
 
-os.system("cargo init")
+os.system("cargo init --lib")
+os.system("cargo add aoc-utils")
 
-shutil.rmtree("./src")
-shutil.copytree(UTILS_SRC_DIRECTORY, "./src")
+with open(TEMPLATE_LIB) as file:
+    template = file.read()
+with open("./src/lib.rs", "w") as file:
+    file.write(
+        template.replace("DayXX", f"Day{day}").replace("DAY_NUMBER", str(int(day)))
+    )
 
+# Register the new day with the `aoc` runner
+os.system("cargo add --package aoc --path .")
+with open(DAYS_REGISTRY) as file:
+    registry = file.read()
+with open(DAYS_REGISTRY, "w") as file:
+    file.write(registry.replace("];", f"    &{dir_path}::Day{day},\n];", 1))
+
+os.makedirs("../inputs/2024", exist_ok=True)
 os.system(
-    f"curl --cookie {cookie.COOKIE} https://adventofcode.com/2024/day/{day}/input > input.txt"
+    f"curl --cookie {cookie.COOKIE} https://adventofcode.com/2024/day/{day}/input > ../inputs/2024/{dir_path}.txt"
 )
@@ -0,0 +1,71 @@
+use aoc_utils::{Example, ParseError, Solution, parse};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("11").part2("31")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((left_input, right_input): &Self::Input) -> i32 {
+        puzzle_part_one(&mut left_input.clone(), &mut right_input.clone())
+    }
+
+    fn part2((left_input, right_input): &Self::Input) -> i32 {
+        puzzle_part_two(&mut left_input.clone(), &mut right_input.clone())
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let pairs = input
+        .split("\n")
+        .map(|line| {
+            let (left, right) = parse::split_once(line, "   ")?;
+            // Part 2 counts the numbers in an array indexed by them.
+            Ok((
+                parse::number_in(left, 0..100000)?,
+                parse::number_in(right, 0..100000)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(pairs.into_iter().unzip())
+}
+
+fn puzzle_part_one(left_list: &mut [i32], right_list: &mut [i32]) -> i32 {
+    left_list.sort();
+    right_list.sort();
+    left_list
+        .iter_mut()
+        .zip(right_list)
+        .map(|(a, b)| (*a - *b).abs())
+        .sum()
+}
+
+fn puzzle_part_two(left_list: &mut [i32], right_list: &mut [i32]) -> i32 {
+    let mut frequency_list = [0; 100000];
+    right_list
+        .iter_mut()
+        .for_each(|n| frequency_list[*n as usize] += 1);
+    left_list
+        .iter_mut()
+        .map(|n| *n * frequency_list[*n as usize])
+        .sum()
+}
+
+const TEST_INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+aoc_utils::example_tests!(Day01);
@@ -0,0 +1,79 @@
+use aoc_utils::{Example, ParseError, Solution, parse};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("2").part2("4")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(matrix: &Self::Input) -> usize {
+        puzzle_1(matrix)
+    }
+
+    fn part2(matrix: &Self::Input) -> usize {
+        puzzle_2(matrix)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .split("\n")
+        .map(|line| {
+            let report = parse::whitespace_list::<i32>(line)?;
+            if report.is_empty() {
+                return Err(ParseError::new(line, "a report with at least one level"));
+            }
+            Ok(report)
+        })
+        .collect()
+}
+
+fn puzzle_1(reports: &[Vec<i32>]) -> usize {
+    reports
+        .iter()
+        .map(|report| if is_safe(report) { 1 } else { 0 })
+        .sum()
+}
+fn puzzle_2(reports: &[Vec<i32>]) -> usize {
+    reports
+        .iter()
+        .map(|report| if is_safe_dampened(report) { 1 } else { 0 })
+        .sum()
+}
+
+fn is_safe(report: &[i32]) -> bool {
+    let increasing = report
+        .windows(2)
+        .all(|pair| (-3..0).contains(&(pair[0] - pair[1])));
+    let decreasing = report
+        .windows(2)
+        .all(|pair| (1..4).contains(&(pair[0] - pair[1])));
+    increasing || decreasing
+}
+
+fn is_safe_dampened(report: &[i32]) -> bool {
+    (0..report.len()).any(|n| {
+        let mut report_sliced = report.to_vec();
+        report_sliced.remove(n);
+        is_safe(&report_sliced)
+    })
+}
+
+const TEST_INPUT: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+aoc_utils::example_tests!(Day02);
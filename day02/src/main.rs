@@ -1,1060 +0,0 @@
-fn main() {
-    let matrix = parse_input(INPUT);
-    let result_1 = puzzle_1(&matrix);
-    println!("Result 1: {result_1}");
-    let result_2 = puzzle_2(&matrix);
-    println!("Result 2: {result_2}");
-}
-
-fn parse_input(input: &str) -> Vec<Vec<i32>> {
-    input
-        .split("\n")
-        .map(|line| {
-            line.split(" ")
-                .map(|n| n.parse::<i32>().expect("should have been an int"))
-                .collect::<Vec<i32>>()
-        })
-        .collect()
-}
-
-fn puzzle_1(reports: &[Vec<i32>]) -> usize {
-    reports
-        .iter()
-        .map(|report| if is_safe(report) { 1 } else { 0 })
-        .sum()
-}
-fn puzzle_2(reports: &[Vec<i32>]) -> usize {
-    reports
-        .iter()
-        .map(|report| if is_safe_dampened(report) { 1 } else { 0 })
-        .sum()
-}
-
-fn is_safe(report: &[i32]) -> bool {
-    let increasing = (0..report.len() - 1).all(|i| (-3..0).contains(&(report[i] - report[i + 1])));
-    let decreasing = (0..report.len() - 1).all(|i| (1..4).contains(&(report[i] - report[i + 1])));
-    increasing || decreasing
-}
-
-fn is_safe_dampened(report: &[i32]) -> bool {
-    for n in 0..report.len() {
-        let mut report_sliced = report.to_vec();
-        report_sliced.remove(n);
-        let increasing = (0..report_sliced.len() - 1)
-            .all(|i| (-3..0).contains(&(report_sliced[i] - report_sliced[i + 1])));
-        let decreasing = (0..report_sliced.len() - 1)
-            .all(|i| (1..4).contains(&(report_sliced[i] - report_sliced[i + 1])));
-        if increasing || decreasing {
-            return true;
-        }
-    }
-    false
-}
-
-const TEST_INPUT: &str = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
-
-const INPUT: &str = "55 56 59 62 61
-68 70 71 74 75 76 78 78
-52 55 56 58 62
-73 76 79 82 84 85 87 94
-1 4 5 6 3 4
-77 80 78 80 83 81
-69 72 73 71 71
-14 15 17 18 17 21
-39 42 43 45 47 46 53
-72 73 73 74 75
-48 49 51 51 53 54 51
-39 40 41 41 43 43
-14 16 19 20 23 23 25 29
-69 70 70 73 80
-79 80 81 84 88 89 92 93
-7 10 13 17 18 16
-67 70 73 75 79 81 84 84
-81 82 84 88 89 93
-76 79 81 83 84 88 94
-71 72 73 80 82 83
-24 26 29 36 33
-69 70 72 73 76 81 81
-6 9 12 17 21
-45 46 52 55 58 59 66
-24 23 24 25 26 29 32
-45 43 45 48 49 51 50
-3 2 5 6 7 9 11 11
-27 25 26 29 31 32 33 37
-17 15 18 19 22 24 30
-69 68 71 70 72
-38 36 38 41 44 41 38
-16 14 12 15 15
-37 34 36 33 36 39 40 44
-80 77 78 81 79 86
-57 55 57 57 60
-31 29 30 30 28
-77 76 79 79 79
-75 73 74 76 76 80
-14 11 11 12 15 22
-72 69 72 74 78 80 81
-80 77 79 83 85 87 86
-32 29 33 36 37 39 41 41
-7 5 9 10 14
-18 17 20 23 24 26 30 35
-86 84 87 89 94 95 98
-32 29 30 37 39 38
-27 26 28 30 31 38 41 41
-20 18 25 27 31
-84 81 83 88 89 94
-21 21 24 27 28 31 32
-15 15 18 21 24 22
-42 42 43 46 47 47
-35 35 38 39 43
-61 61 63 65 67 74
-23 23 21 24 27 29
-8 8 6 9 10 9
-84 84 83 86 86
-67 67 69 71 73 70 74
-85 85 84 87 93
-9 9 11 14 17 17 20
-33 33 34 34 31
-65 65 68 68 71 71
-60 60 62 62 63 67
-11 11 14 14 20
-18 18 21 24 26 28 32 34
-43 43 44 47 51 52 55 52
-58 58 61 65 67 68 68
-55 55 57 61 63 67
-72 72 76 78 79 80 85
-1 1 2 9 12 13
-80 80 83 89 90 93 92
-24 24 26 33 36 38 38
-17 17 18 19 26 30
-21 21 23 26 33 34 37 44
-30 34 37 39 41 44
-24 28 30 31 32 35 38 36
-84 88 90 91 92 95 95
-60 64 67 70 73 76 79 83
-9 13 15 16 19 21 22 27
-46 50 51 52 49 50 51 53
-68 72 71 73 74 72
-14 18 16 19 21 23 24 24
-50 54 55 58 55 58 62
-76 80 82 81 82 85 88 93
-66 70 73 73 76 78 81 83
-37 41 42 42 43 45 43
-7 11 14 14 14
-5 9 11 11 12 16
-10 14 17 17 18 21 22 28
-32 36 38 42 43
-51 55 59 62 63 64 65 63
-14 18 19 20 24 27 29 29
-33 37 38 39 41 45 46 50
-74 78 82 85 88 89 96
-56 60 65 67 69
-30 34 36 38 43 42
-74 78 79 80 85 85
-75 79 86 87 89 91 93 97
-78 82 84 90 92 93 99
-65 72 75 77 80 82
-82 89 91 93 94 95 93
-49 56 59 62 62
-18 25 27 28 30 34
-46 52 54 56 57 62
-13 19 21 22 23 21 23
-26 32 29 31 32 31
-91 96 98 95 95
-47 52 54 51 53 55 58 62
-79 84 81 82 83 90
-75 82 82 83 86
-76 83 83 84 86 84
-9 14 17 17 17
-26 32 35 37 39 39 40 44
-40 45 45 46 53
-58 65 69 71 72 74
-34 40 44 46 48 45
-46 53 55 56 60 63 63
-18 25 29 30 34
-4 9 10 11 15 22
-51 56 61 63 64 67 70
-13 18 23 25 28 31 32 29
-16 21 27 28 28
-25 32 34 41 43 47
-29 34 37 44 47 50 55
-36 34 33 31 28 25 22 23
-27 25 22 21 21
-15 12 9 7 6 5 1
-47 46 45 44 37
-80 78 79 77 74 73 70
-16 13 11 8 5 2 4 6
-51 50 48 49 47 46 43 43
-21 18 19 17 15 13 9
-41 40 38 40 39 33
-9 8 6 6 5
-44 41 41 40 38 41
-99 96 93 93 93
-40 38 36 36 33 30 27 23
-27 25 24 24 22 17
-93 92 88 86 85 83 81
-49 46 45 41 38 41
-71 69 65 63 61 59 58 58
-51 50 48 44 41 40 38 34
-44 41 37 34 32 31 30 25
-14 13 8 5 4 2
-32 31 28 21 20 19 16 18
-39 36 35 32 27 26 26
-49 48 46 39 35
-56 54 53 47 40
-54 55 54 52 49 48 47
-78 79 78 75 74 73 76
-95 96 94 91 89 86 85 85
-73 74 72 70 69 65
-91 93 92 90 88 83
-81 84 83 86 84 83
-48 50 49 47 49 48 45 46
-88 90 88 87 89 87 87
-47 49 48 46 43 46 42
-47 48 47 46 48 42
-23 24 21 18 18 17
-66 67 67 65 68
-50 53 52 51 51 48 48
-89 92 92 90 89 85
-92 93 91 88 87 85 85 80
-88 91 88 86 82 80
-74 77 73 72 73
-30 32 29 26 24 20 19 19
-88 89 88 84 80
-42 44 40 38 35 34 27
-94 95 94 88 86
-18 19 17 14 7 4 5
-70 72 69 63 62 62
-28 30 28 27 26 19 17 13
-57 60 58 56 55 50 44
-68 68 66 63 61
-45 45 42 41 42
-76 76 74 71 68 68
-81 81 79 78 77 73
-98 98 96 95 92 87
-58 58 57 60 57 54 53
-92 92 89 87 89 87 86 89
-83 83 80 79 82 80 80
-52 52 50 47 45 46 43 39
-41 41 43 40 38 33
-57 57 56 53 52 52 50 49
-88 88 85 84 83 83 84
-72 72 70 67 65 65 65
-61 61 61 60 56
-61 61 58 57 55 53 53 48
-59 59 58 54 53 52 51 50
-45 45 44 43 41 37 39
-78 78 76 74 73 69 69
-86 86 82 81 79 76 73 69
-36 36 32 30 23
-24 24 23 22 21 18 11 8
-15 15 13 6 4 5
-41 41 38 33 32 32
-13 13 7 5 1
-74 74 71 68 65 60 54
-88 84 82 79 78 75 72 70
-91 87 84 81 80 79 82
-74 70 68 67 67
-58 54 53 52 48
-95 91 89 86 79
-51 47 50 49 48 45 44
-36 32 31 29 31 29 26 29
-86 82 80 81 80 77 77
-43 39 36 39 36 32
-77 73 76 74 71 68 66 60
-95 91 91 88 85
-12 8 8 6 5 7
-53 49 48 48 47 46 46
-30 26 26 25 21
-68 64 62 60 58 58 57 51
-45 41 40 36 35 34
-23 19 15 12 15
-38 34 30 27 27
-54 50 46 45 43 40 36
-37 33 29 27 24 19
-56 52 51 49 43 42
-66 62 56 55 52 49 51
-65 61 58 56 54 51 44 44
-70 66 65 62 59 56 50 46
-98 94 92 85 83 82 81 75
-52 45 43 41 39
-43 38 37 34 37
-57 51 50 49 48 47 44 44
-38 31 29 27 26 23 19
-23 16 14 12 7
-36 30 31 29 28
-12 6 8 5 8
-20 13 12 11 12 9 9
-93 86 84 83 84 82 80 76
-55 48 49 47 42
-17 11 9 6 6 3
-32 26 26 23 20 23
-89 84 83 83 83
-79 72 70 70 66
-56 49 48 48 45 40
-61 54 50 48 45 44
-24 18 16 12 11 9 10
-69 62 61 57 57
-34 28 27 23 20 19 15
-85 78 74 72 70 69 66 61
-59 53 52 51 46 44 43 42
-65 58 51 48 49
-98 91 86 83 83
-48 42 41 35 33 29
-47 41 40 37 31 28 21
-52 53 56 59 60 58
-62 63 65 66 67 67
-62 65 66 68 71 75
-42 44 46 47 48 51 53 59
-89 91 94 93 96 99
-29 32 34 35 36 34 31
-28 29 26 28 31 31
-74 75 73 75 77 79 81 85
-46 47 44 47 48 54
-4 6 8 8 11 12 15 18
-80 81 81 82 79
-51 52 54 56 56 59 59
-49 51 51 53 56 60
-47 50 53 55 55 62
-6 8 12 15 17 19 21
-86 89 90 92 96 98 99 96
-77 78 80 83 87 87
-55 58 60 63 66 70 71 75
-55 57 61 64 69
-83 84 85 92 93 95
-77 78 83 84 83
-26 29 34 37 37
-20 21 23 26 27 33 35 39
-28 29 30 35 36 37 39 45
-62 61 64 67 68 70
-20 18 21 23 26 28 31 29
-72 70 73 76 79 80 80
-48 46 49 52 53 55 56 60
-9 6 7 8 9 10 13 19
-69 68 65 66 69 72 75
-40 38 39 40 39 40 38
-50 48 49 48 48
-81 79 80 79 83
-15 14 13 16 18 23
-10 9 12 14 14 16 18 20
-96 95 98 98 99 96
-79 77 79 79 79
-61 59 61 62 63 63 66 70
-17 16 17 18 19 19 21 28
-22 21 25 27 28
-35 33 34 38 41 39
-62 61 64 65 69 69
-46 44 47 51 53 55 59
-46 44 46 49 50 51 55 62
-41 39 46 48 50
-39 38 45 46 45
-78 75 82 85 85
-22 21 23 26 28 34 38
-6 5 12 13 15 18 24
-58 58 59 62 65
-21 21 23 24 26 29 32 31
-96 96 98 99 99
-84 84 85 87 91
-68 68 71 74 81
-6 6 7 10 11 10 11 14
-31 31 33 36 38 36 34
-54 54 55 57 59 61 60 60
-31 31 33 35 34 37 41
-25 25 27 24 25 30
-82 82 85 85 86 88 90
-39 39 41 43 43 46 45
-39 39 42 43 46 46 46
-88 88 91 91 95
-81 81 83 85 85 86 87 93
-36 36 40 41 43 44 46
-2 2 5 9 8
-8 8 11 12 16 18 18
-79 79 81 85 89
-47 47 50 54 61
-77 77 79 81 88 89
-60 60 63 66 68 74 75 74
-76 76 83 85 87 87
-34 34 41 43 47
-65 65 68 71 76 81
-3 7 8 11 12 14 17
-53 57 59 60 61 60
-28 32 33 34 36 37 37
-45 49 51 53 54 57 61
-55 59 61 63 68
-43 47 50 51 50 53 55 57
-1 5 7 10 13 12 11
-43 47 48 51 48 51 51
-80 84 82 83 85 89
-38 42 44 45 46 44 49
-41 45 47 48 50 53 53 56
-2 6 6 8 11 12 14 12
-10 14 17 20 20 21 24 24
-12 16 18 21 23 23 26 30
-30 34 37 39 42 44 44 49
-53 57 59 62 64 68 70
-68 72 76 77 75
-9 13 16 20 22 22
-78 82 83 87 89 91 92 96
-12 16 17 19 23 25 28 35
-76 80 86 89 90 93
-86 90 91 92 98 99 98
-61 65 68 71 76 76
-24 28 30 36 40
-31 35 37 40 41 46 49 56
-43 50 51 52 55 58 60
-43 48 51 54 56 59 58
-55 62 65 68 70 71 74 74
-10 16 19 20 21 23 25 29
-80 87 89 92 97
-91 96 94 97 99
-82 87 88 85 87 86
-16 23 20 21 24 24
-20 25 22 25 28 32
-27 34 37 36 37 38 45
-80 85 88 91 93 96 96 99
-47 52 54 54 57 60 58
-62 68 70 71 73 73 73
-21 28 29 29 31 34 35 39
-40 45 46 46 47 52
-61 68 70 74 77 79 80 81
-76 81 84 85 87 88 92 91
-79 85 86 90 90
-57 62 65 69 73
-11 18 21 25 28 29 31 37
-74 79 86 87 89 90 91 93
-5 12 14 19 21 23 24 22
-36 43 45 47 48 53 53
-27 34 39 40 44
-64 69 75 78 81 82 88
-55 54 52 51 52
-73 70 68 67 67
-83 82 81 80 79 76 73 69
-24 23 22 19 18 16 14 8
-99 97 95 96 95 92
-23 20 21 20 19 17 14 15
-25 23 24 23 21 18 18
-53 51 49 47 48 46 42
-87 84 82 85 80
-95 93 91 88 88 86 83
-90 87 85 84 83 83 85
-69 66 63 63 61 60 60
-13 11 11 10 6
-59 57 57 56 51
-43 40 37 35 33 29 26 25
-14 13 9 8 6 5 8
-77 74 72 70 66 64 64
-46 44 41 37 33
-59 58 54 51 46
-55 52 45 43 42 41 40
-39 36 35 34 33 28 29
-47 45 40 39 39
-47 45 43 37 35 33 32 28
-96 94 92 87 84 81 79 72
-43 46 45 44 42 40 37
-79 82 80 77 74 73 71 74
-42 44 43 41 40 37 37
-57 58 57 56 53 52 48
-78 79 78 75 74 71 66
-31 32 31 34 31
-25 28 27 29 30
-37 38 37 35 36 34 34
-68 71 70 73 71 67
-20 22 25 22 21 14
-98 99 98 95 95 94
-84 86 83 82 82 80 77 80
-57 59 57 57 56 55 55
-24 27 25 25 22 19 16 12
-53 54 51 48 48 45 38
-28 29 27 26 22 19
-42 44 43 39 36 38
-88 91 90 89 86 82 82
-91 94 92 88 86 82
-27 29 27 23 20 18 11
-16 17 16 10 9
-69 70 67 60 58 57 56 58
-28 31 30 27 26 19 18 18
-58 61 58 57 50 47 43
-69 72 71 66 64 63 61 55
-49 49 47 45 43
-38 38 35 32 29 32
-62 62 59 58 57 56 53 53
-79 79 78 77 73
-28 28 27 25 24 22 16
-49 49 51 50 47 46
-37 37 36 34 31 33 36
-36 36 37 35 35
-50 50 47 45 46 43 42 38
-39 39 38 36 39 34
-28 28 28 27 24 22 21 19
-21 21 19 17 15 15 13 14
-30 30 30 28 25 23 20 20
-79 79 79 78 75 73 70 66
-47 47 47 46 40
-72 72 70 66 64 62
-49 49 45 44 45
-82 82 80 76 74 72 72
-26 26 22 20 16
-71 71 67 64 63 60 54
-95 95 93 87 86
-15 15 14 13 12 6 8
-69 69 66 64 58 58
-36 36 34 33 26 23 22 18
-22 22 17 15 10
-33 29 27 26 25 22
-25 21 18 15 14 17
-71 67 64 61 61
-71 67 65 62 59 58 54
-89 85 82 79 77 74 72 67
-52 48 47 50 47 46 44 42
-55 51 49 46 49 46 48
-8 4 2 5 5
-86 82 84 83 79
-38 34 32 31 28 29 24
-49 45 42 40 40 38 35 32
-24 20 17 15 15 12 15
-72 68 65 65 65
-67 63 61 58 56 53 53 49
-45 41 40 38 36 36 29
-44 40 37 33 30 28
-24 20 17 13 11 8 10
-60 56 54 50 49 48 48
-30 26 25 21 17
-26 22 20 17 13 12 5
-32 28 26 20 17
-43 39 38 33 30 28 26 29
-94 90 85 82 82
-81 77 71 68 66 62
-99 95 94 89 88 81
-21 16 15 14 13 12
-83 76 75 74 73 70 69 71
-73 68 66 65 63 62 59 59
-44 37 35 32 28
-91 86 83 82 81 79 78 72
-96 90 92 89 86
-33 27 25 28 25 23 24
-32 27 24 22 20 23 20 20
-64 57 60 57 53
-43 36 33 30 28 31 26
-10 5 5 3 2
-65 60 58 58 55 58
-69 63 63 61 61
-70 65 62 61 61 57
-56 51 50 48 45 45 42 36
-75 70 66 65 62
-17 11 7 5 6
-36 29 25 22 20 19 18 18
-26 20 18 14 13 9
-43 38 35 34 32 30 26 19
-66 60 54 52 49
-54 47 41 38 37 35 36
-40 33 26 25 25
-31 26 24 22 19 13 12 8
-80 73 71 68 62 57
-11 8 9 16 17 19 22 22
-25 25 27 26 25 24 22 20
-71 75 78 82 85 88 91
-24 26 29 33 34 37 40 42
-39 45 45 46 47 49 51
-42 42 44 45 48 52
-92 95 92 92 92
-53 56 55 53 53 50 46
-79 75 72 71 70 67 67
-26 26 23 19 18 14
-86 85 88 91 92 95 96 98
-35 35 38 37 38
-12 12 13 11 6
-27 23 26 24 23 23
-35 39 42 43 46 49 52 54
-30 24 24 21 18 15 14 14
-61 55 52 50 48 46 43 46
-9 7 9 10 8
-67 68 66 59 55
-52 56 59 60 63 64 68 65
-11 14 16 19 22 29 30 35
-52 58 59 65 66
-40 45 48 53 55 59
-47 47 50 52 56 58 62
-37 41 41 44 47 50
-22 19 16 15 18
-21 21 23 27 30
-68 72 73 74 76 77 80 84
-61 66 67 71 72 75 73
-60 63 62 55 54
-46 50 53 55 55 59
-60 58 59 58 55 51
-72 68 61 58 56 55 58
-25 24 27 28 30 34 38
-14 17 18 20 22 25 23 23
-47 40 37 35 34
-57 58 59 65 67 66
-74 69 62 61 60 57 58
-4 10 14 16 17 20
-13 18 19 20 19 22 29
-54 54 51 50 45
-72 69 71 73 70 74
-84 79 77 80 79 74
-25 25 25 24 22
-86 86 85 85 82 80 77 79
-68 65 67 72 73 74
-29 29 27 27 24 21 21
-59 61 63 62 65 69
-45 42 41 38 36 32 31
-45 38 39 37 33
-79 76 76 73 72
-31 32 31 27 21
-9 9 8 9 10 12 14 17
-47 51 54 57 57
-26 25 26 29 30 37
-91 90 88 85 81 78 74
-53 56 52 50 50
-51 47 46 43 42 39 33
-84 77 76 72 69 63
-41 40 39 41 39 38 37 37
-74 78 76 79 79
-64 64 59 57 56 56
-69 72 69 70 72 75 82
-27 32 35 36 34
-31 26 25 21 20 19 15
-67 67 70 76 79 84
-33 35 36 37 39 41 42 39
-35 32 32 29 29
-57 54 52 49 47 46 46 47
-1 5 8 10 12 13 10 13
-37 38 42 45 46 49 55
-86 91 92 94 94 92
-88 86 83 80 78 77 73
-3 5 6 10 10
-24 24 27 30 30 34
-17 15 13 16 18 15
-57 54 52 52 51 50 46
-46 43 45 46 49 51 51 52
-70 68 65 63 58
-49 55 59 60 60
-53 46 45 45 42 41 40 35
-47 51 52 58 65
-27 29 26 23 25
-23 19 16 10 8 5 1
-98 91 91 89 92
-59 59 58 55 54 53 51
-56 63 60 62 64 67 69
-3 9 9 11 14 14
-86 83 84 82 80 74
-74 77 80 80 83 85 88 88
-24 20 20 17 10
-56 62 64 65 66 69 73
-1 5 7 9 15 12
-60 63 67 70 69
-63 66 69 70 76 79 82
-38 38 36 34 32 30 31
-44 47 46 45 44 44 41 40
-66 66 67 64 71
-1 7 8 9 11 14
-13 16 15 14 12 11 11
-75 72 66 65 64 64
-56 59 56 59 57 56 52
-27 20 17 16 10 6
-19 23 27 30 30
-95 98 97 93 90
-33 37 37 40 42 40
-36 42 44 42 43 46 50
-94 88 82 79 78 71
-17 13 11 9 5 4 4
-88 88 89 91 92 95 95 92
-97 93 90 88 85 88 86 83
-15 12 12 14 17 24
-7 10 9 8 11 8 2
-59 55 54 51 47
-60 57 59 57 58
-74 79 82 85 89 94
-24 19 15 12 10 7
-12 7 5 2 2
-38 36 33 34 37 38 38
-36 32 32 31 27
-15 20 21 28 31 37
-79 79 80 82 80
-95 91 90 89 86 83 80 81
-89 86 86 87 89 93
-3 6 4 6 3 4
-65 61 59 56 55 53 52 51
-27 26 28 31 38 39 45
-81 84 87 91 95
-71 71 68 66 65 62 59 59
-28 28 27 25 25 19
-90 94 96 97 94
-63 63 67 68 69 66
-68 68 66 65 60 59 58 51
-92 92 86 85 82 80 76
-26 27 24 25 26 25
-73 78 84 86 88 91 91
-42 44 43 40 42 40 40
-73 80 82 85 85
-44 44 46 53 56
-29 25 22 20 16 15 18
-33 30 35 38 39 42 44 48
-87 87 85 80 79 78
-37 44 45 43 42
-87 87 89 91 95 97 97
-82 82 83 83 84 86 88 88
-90 90 88 90 92 95 97 97
-82 85 88 87 84 83 82 81
-70 70 73 75 77 83
-57 55 54 56 57 59
-26 19 16 11 9
-64 65 63 61 57 54 57
-81 85 86 87 92 93 95
-10 14 18 21 22 25 32
-70 66 63 61 58 52 49 46
-35 30 27 25 22 25 23
-71 77 77 79 81 82 83 90
-17 16 17 18 17 19 21 28
-31 30 23 21 24
-47 47 50 51 54 56 59 59
-74 74 71 73 70 67 64 60
-6 11 15 17 18 22
-59 55 55 53 51 51
-51 45 42 39 42 41 41
-22 29 32 30 32 32
-58 56 57 61 62 63 66 68
-16 16 12 9 7 6 8
-61 57 53 52 50 44
-60 58 55 58 55
-45 45 50 52 54 57 60 64
-31 28 29 31 35 37 40 40
-86 86 89 91 94
-32 25 23 16 13 13
-83 82 81 78 75 72 69 69
-52 56 59 61 62 63 61 66
-56 50 49 46 40
-15 18 18 17 20
-18 15 11 8 6 8
-72 72 71 67 62
-85 87 85 79 81
-36 39 37 35 33 31 31 24
-41 40 37 33 30 29 22
-63 67 67 69 71 72 79
-41 39 41 45 44
-20 23 25 26 28 29 32 36
-45 49 52 49 51 52 54 58
-48 46 40 37 30
-52 54 54 57 63
-8 11 14 17 19 18 19
-99 99 92 90 93
-14 12 13 14 20 21 24 22
-56 60 61 62 63 65 70
-38 34 36 33 31 30 28 24
-35 33 32 30 28 26 19 15
-66 68 65 62 55 48
-15 9 11 10 7 5 7
-82 82 85 92 95 97 97
-80 80 81 80 82 83 84 88
-40 36 38 35 33 28
-97 95 92 90 87 85 84
-76 77 80 83 84 87 88 90
-81 80 78 77 75 74
-94 93 91 89 88 86
-85 82 79 76 74 73 72 71
-73 71 69 66 64 63
-17 18 21 24 26 29 30
-72 73 76 79 82 84
-16 14 11 10 9 8 6
-25 27 30 33 35
-30 31 34 37 39
-82 84 87 90 93 95
-21 19 16 14 13 11 9
-43 46 49 50 52 55 57
-70 69 67 66 65 63 61 60
-29 26 23 22 20 17 14
-74 73 71 68 66 65 64
-85 83 80 79 77 76 73 71
-10 11 14 16 17 18 20
-47 44 42 39 38 35 32 30
-67 68 70 71 74 76 77 79
-35 37 40 43 45 46 48 51
-66 64 61 58 55 53 52 49
-76 79 80 81 82 83 86
-56 54 51 49 47
-67 64 63 60 58 57 54 52
-10 12 14 16 18 21 24
-79 82 83 84 86 87 89 90
-79 76 75 74 73 70 67
-48 51 52 53 54 57
-42 41 38 35 34 32 29 28
-2 5 6 8 10 11 14 15
-77 75 73 72 69 67
-81 84 87 90 92 93 94
-29 32 35 36 39 42 43 44
-25 24 23 20 19 17 16
-64 67 69 70 73 74
-17 19 20 21 22 24 25
-42 43 44 45 48 50 52 54
-45 42 41 38 37
-80 78 75 72 69 66 63 62
-8 9 11 14 15 18 20 22
-52 51 50 47 44
-23 20 19 18 16 15 14
-18 21 24 25 26
-86 88 89 91 93 94 95 97
-5 6 9 11 14 17 18
-74 73 72 71 70 68
-70 73 75 77 80
-56 59 60 61 62 64 67
-21 24 26 29 32 34 35 37
-31 34 36 37 38
-18 15 13 10 8 7 4
-36 39 42 44 45 47
-71 74 76 77 78 79 82
-31 34 36 38 39 42
-20 22 23 24 26 28 29
-72 71 70 67 64 62 61 58
-71 74 75 76 77 78 80 82
-49 50 53 56 57 58 61
-15 14 13 10 7 6 3
-28 26 24 21 19 16 14
-50 47 46 45 43
-37 36 35 32 30
-71 69 66 63 62 61 60
-62 64 66 68 69 72 74 76
-40 41 42 45 48 50
-20 17 16 15 12 11 10
-9 10 13 15 17 20 23 24
-38 39 42 43 44 45
-18 19 20 23 24 27 28 29
-5 8 9 12 14 16 17
-58 55 54 53 51 49
-15 18 21 23 25
-20 19 18 16 15 13
-69 67 66 63 60 58 57
-64 61 60 57 54 52 50 49
-20 22 25 26 27 28
-42 45 48 50 52 55 57
-18 17 15 13 12 10
-22 19 16 15 14 11 9
-67 64 61 58 55 53 51
-50 52 53 56 59 62 65 68
-52 55 57 58 60 62
-40 38 37 35 32 31 30
-69 68 66 65 63 61 58
-49 48 45 42 40 37 34
-37 36 34 31 28
-52 53 56 59 60
-39 41 42 43 45 48 51 52
-15 16 19 22 23
-84 86 89 91 92 95 97 99
-83 86 88 90 92 95
-63 64 67 68 71
-90 87 85 83 82 79
-56 58 60 62 63 64 66 69
-14 12 10 9 6
-43 42 41 40 37
-18 16 15 14 11
-57 58 59 61 64 66
-60 59 56 55 52 49 48
-19 21 22 23 26 29
-49 47 46 43 40
-84 85 87 89 91 94 96
-61 60 58 56 53 50 47 44
-66 67 70 73 75 78 80 81
-27 25 22 19 18 15 13 10
-44 47 48 49 52 53 54 56
-17 14 11 9 6 4
-94 91 88 86 85
-58 57 55 52 51
-90 89 87 85 84 81 80 78
-35 37 38 41 44 45
-38 39 40 41 43
-89 90 92 93 94 97 98
-62 59 58 55 54 52 51 49
-9 12 14 16 17 19 22 24
-94 92 89 86 85 84 81 78
-28 30 31 34 35 36 38 41
-59 61 64 65 66 69 70 72
-77 76 75 72 71 70 68 65
-55 54 52 49 48 47 44
-96 94 93 92 89
-40 41 42 45 46 48 50 53
-52 53 54 56 58 60 62
-98 97 94 92 89 88 87 86
-68 69 72 75 77 80
-49 48 47 44 41 39 36
-57 56 53 51 50 48 47
-11 14 15 17 20 23 26 28
-21 19 18 16 14
-49 51 54 57 60 63 66 69
-50 47 46 44 42 41 39
-45 42 41 39 36 33 32 30
-47 48 49 52 53 55 56
-79 80 82 83 85 86 87 90
-31 28 27 26 23
-3 6 7 10 13
-84 85 88 91 94 95 96
-59 57 55 52 50 49
-55 52 49 48 45 44
-13 14 16 18 19 20
-70 71 74 77 78 80 83
-36 38 39 42 45 46 49 50
-95 93 90 87 86 85 83
-14 17 20 22 25
-20 18 16 15 14 13 12 9
-32 34 35 36 39 40 41
-31 34 36 37 39 40 43
-73 72 70 67 64
-47 48 50 53 54
-91 88 85 84 83 81 79 77
-49 46 44 41 39 37 36
-20 19 18 17 16 15 12
-86 88 89 92 93 96
-78 80 81 84 87 88 90 91
-22 20 19 18 16 13
-2 3 6 7 10 11
-41 38 37 34 33 30 28
-28 27 24 23 21 20 17 14
-74 77 80 81 82 84
-69 67 65 64 63 62
-81 79 78 76 73 72
-54 53 52 50 47
-17 16 15 13 11
-57 59 60 62 64
-23 25 27 30 32 34
-29 26 25 24 23
-30 28 26 25 22 19 18
-70 67 64 61 58 57
-62 64 65 68 71
-83 86 89 92 93 94 96 99
-47 45 44 43 40 37 35 34
-47 48 49 52 55 57 59 60
-3 4 7 10 13 15 18
-64 66 68 69 70 73
-18 21 24 25 27 29
-73 70 68 65 62 59 58 56
-68 65 62 60 59 57 54 52
-11 8 6 3 2
-28 29 31 32 34 35 36
-37 38 41 44 46 48 49
-60 61 64 67 70 73 75 76
-22 23 25 28 29
-84 87 90 92 95 96 99
-31 33 34 35 37 40 41 42
-70 68 67 65 63
-82 84 87 88 90 92 94 95
-86 88 89 92 94
-72 69 68 67 65 64 63
-79 82 85 86 87 90
-29 30 32 33 36 37 39
-36 38 39 42 43 45 47 50
-70 67 66 63 62 60 58 55
-25 26 27 28 31 34
-82 80 77 75 74 73 72 70
-3 6 8 11 12 15
-29 30 33 35 38 41 42 43
-44 45 48 49 52 55 57
-47 49 51 53 56 58
-60 59 57 55 52
-22 25 28 29 31 33
-22 21 19 16 14 13
-19 21 24 26 29 32 34 37
-49 46 43 42 40 37 34
-40 37 35 33 31 29 27 25
-84 82 80 77 74 71 70
-75 73 70 67 64 61 59
-55 52 51 49 48
-71 74 76 77 79 81
-57 59 61 63 64 67 70
-55 54 53 52 50 49 46 45
-44 47 49 51 52 53 55 57
-84 87 88 91 94
-79 77 76 73 70 67 65 64
-48 47 44 42 39 38 36 35
-38 36 35 34 32 29 27 26
-58 61 64 67 70
-83 85 87 89 90 91 93
-70 69 68 66 64 62 60 58
-25 22 20 17 16
-59 62 64 67 70 73 74
-56 55 54 51 50
-36 37 38 41 43 44 47
-48 46 45 42 41 40 37
-41 40 39 37 35 33 31 29
-52 49 46 44 43 42
-72 73 75 76 78 79 81 84
-75 77 80 81 84 87 88 90
-28 29 31 32 34 37
-20 22 24 27 28 31 33 34
-39 37 34 31 29 26 23 20
-15 16 18 19 21 24
-36 38 40 41 44 47 48 49
-10 12 14 17 18 19 22
-27 24 23 22 19
-90 87 86 85 82 79 76
-34 35 36 37 40 42 45
-66 64 62 59 56 55 54
-60 62 64 65 66 67 70
-37 38 40 41 42 43
-79 78 77 74 73 70 68 65
-22 21 19 18 15
-95 94 93 92 89 88
-75 72 69 66 65
-25 22 19 16 14 13 12 9
-30 31 32 33 35
-5 6 7 9 10 13 14 17
-82 83 85 87 89 92 93 94
-54 52 51 48 46 45
-35 32 29 28 27 25 23 20
-51 49 46 43 42 40
-39 38 35 32 30 27
-48 45 42 40 38 37 35
-59 58 56 53 51 48 45
-76 75 73 70 68 66 63
-68 71 74 77 80 81 82
-28 29 31 34 35
-88 90 91 92 94 95 98 99
-69 68 65 64 62
-88 86 83 81 80
-85 88 89 92 93 94 96
-38 36 34 33 30 27 26 25
-69 70 71 73 74 77
-22 24 27 29 32 33 35
-80 81 83 85 87 89 91 92
-47 49 51 53 54 56 58
-45 44 41 38 36 33 30
-16 18 19 21 24 25 26 29
-69 70 73 76 77 80 82
-86 85 83 82 80 77
-7 8 10 11 12 13
-68 65 62 60 57
-20 17 16 14 13 12
-90 87 86 85 83 82 80 79
-20 18 16 14 13 10
-36 38 41 44 47 48
-64 66 69 70 72
-94 91 88 85 83 80
-25 26 29 32 33 36
-26 24 21 18 17
-63 60 59 56 54
-40 39 38 35 32 30
-60 63 65 67 69 71 72
-16 15 12 10 9
-49 51 54 57 58 59 62
-48 49 52 54 55 58 61
-36 35 32 31 29
-73 70 69 66 63 61 59
-16 18 19 22 25
-32 30 28 26 25 22 20
-39 42 45 48 51 54
-70 72 73 74 76 79 80 81
-37 34 31 30 29
-15 16 17 19 21 24
-31 30 28 25 23 20 18 17
-81 82 83 86 89 91 92 94
-78 76 74 72 71
-24 25 28 30 31 32
-80 79 76 75 73 72 71
-71 72 74 76 78 81 84 86
-8 11 14 15 18
-46 43 42 41 38 35 34
-20 18 17 15 14
-84 82 80 79 77 76
-43 44 47 48 50";
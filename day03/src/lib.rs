@@ -0,0 +1,98 @@
+use aoc_utils::{Example, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(TEST_INPUT).part1("161"),
+        Example::new(TEST_INPUT2).part2("48"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(text: &Self::Input) -> i64 {
+        parse_multiplication1(text)
+    }
+
+    fn part2(text: &Self::Input) -> i64 {
+        parse_multiplication2(text, true)
+    }
+}
+
+fn parse_multiplication1(text: &str) -> i64 {
+    if text.is_empty() {
+        return 0;
+    }
+
+    if !text.starts_with("mul(") {
+        return parse_multiplication1(&text[1..]);
+    }
+
+    let (left, right) = match text[4..].split_once(")") {
+        Some(inner) => inner,
+        None => return parse_multiplication1(&text[1..]),
+    };
+
+    let (left_inner, right_inner) = match left.split_once(",") {
+        Some(split) => split,
+        None => return parse_multiplication1(&text[1..]),
+    };
+
+    if let (Ok(left_int), Ok(right_int)) = (left_inner.parse::<i64>(), right_inner.parse::<i64>()) {
+        left_int * right_int + parse_multiplication1(right)
+    } else {
+        parse_multiplication1(&text[1..])
+    }
+}
+
+fn parse_multiplication2(text: &str, enabled: bool) -> i64 {
+    if text.is_empty() {
+        return 0;
+    }
+
+    if let Some(stripped) = text.strip_prefix("do()") {
+        return parse_multiplication2(stripped, true);
+    }
+    if let Some(stripped) = text.strip_prefix("don't()") {
+        return parse_multiplication2(stripped, false);
+    }
+
+    if !enabled {
+        return parse_multiplication2(&text[1..], enabled);
+    }
+
+    if !text.starts_with("mul(") {
+        return parse_multiplication2(&text[1..], enabled);
+    }
+
+    let (left, right) = match text[4..].split_once(")") {
+        Some(inner) => inner,
+        None => return parse_multiplication2(&text[1..], enabled),
+    };
+
+    let (left_inner, right_inner) = match left.split_once(",") {
+        Some(split) => split,
+        None => return parse_multiplication2(&text[1..], enabled),
+    };
+
+    if let (Ok(left_int), Ok(right_int)) = (left_inner.parse::<i64>(), right_inner.parse::<i64>()) {
+        left_int * right_int + parse_multiplication2(right, enabled)
+    } else {
+        parse_multiplication2(&text[1..], enabled)
+    }
+}
+
+const TEST_INPUT: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+const TEST_INPUT2: &str =
+    "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+aoc_utils::example_tests!(Day03);
@@ -1,78 +0,0 @@
-fn main() {
-    let result1 = parse_multiplication1(INPUT);
-    println!("Result1: {}", result1);
-
-    let result2 = parse_multiplication2(INPUT, true);
-    println!("Result1: {}", result2);
-}
-
-fn parse_multiplication1(text: &str) -> i64 {
-    if text.is_empty() {
-        return 0;
-    }
-
-    if !text.starts_with("mul(") {
-        return parse_multiplication1(&text[1..]);
-    }
-
-    let (left, right) = match text[4..].split_once(")") {
-        Some(inner) => inner,
-        None => return parse_multiplication1(&text[1..]),
-    };
-
-    let (left_inner, right_inner) = match left.split_once(",") {
-        Some(split) => split,
-        None => return parse_multiplication1(&text[1..]),
-    };
-
-    if let (Ok(left_int), Ok(right_int)) = (left_inner.parse::<i64>(), right_inner.parse::<i64>()) {
-        left_int * right_int + parse_multiplication1(right)
-    } else {
-        parse_multiplication1(&text[1..])
-    }
-}
-
-fn parse_multiplication2(text: &str, enabled: bool) -> i64 {
-    if text.is_empty() {
-        return 0;
-    }
-
-    if let Some(stripped) = text.strip_prefix("do()") {
-        return parse_multiplication2(stripped, true);
-    }
-    if let Some(stripped) = text.strip_prefix("don't()") {
-        return parse_multiplication2(stripped, false);
-    }
-
-    if !enabled {
-        return parse_multiplication2(&text[1..], enabled);
-    }
-
-    if !text.starts_with("mul(") {
-        return parse_multiplication2(&text[1..], enabled);
-    }
-
-    let (left, right) = match text[4..].split_once(")") {
-        Some(inner) => inner,
-        None => return parse_multiplication2(&text[1..], enabled),
-    };
-
-    let (left_inner, right_inner) = match left.split_once(",") {
-        Some(split) => split,
-        None => return parse_multiplication2(&text[1..], enabled),
-    };
-
-    if let (Ok(left_int), Ok(right_int)) = (left_inner.parse::<i64>(), right_inner.parse::<i64>()) {
-        left_int * right_int + parse_multiplication2(right, enabled)
-    } else {
-        parse_multiplication2(&text[1..], enabled)
-    }
-}
-
-const TEST_INPUT: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-const INPUT: &str = "mul(48,648)mul(637,618):#?^ {/*?<mul(506,718)&:why()@]mul(910,151)who(){*@{where()#mul(624,757)>why()+mul(739,690)mul(74,266)who()%][]*}who()mul(662,558)select()>>! ?!^/mul(973,451)>&from(113,668);@who()?mul(607,783)<+don't()what()mul(78,875)@:when()~mul(665,145)[)]!from()what();:<(mul(866,998)^}/!^mul(312,345) :mul(688,178)(mul(593,958)mul(920,416)-~when()>;,}[select(904,4)mul(7,573)?[*~]mul(327,304)#mul(263,191)@mul(677,760)*mul(846,234)$from()%what()what(248,864)$!&when()#mul(642,508)+&>from()![>@<{mul(469,481)why()select(44,921)>who()>:@[}mul(762,260)%mul(155,860)[mul(886,453))mul(168,7)what())/(mul(602,650)~/:~:%from(881,125)#mul(318,341)^$])mul(183,6)];$mul(785,159) #;$mul(644,162))$who()%$',mul(708,855)>%#-&mul(260,317)(mul(770,310)why()!#,~%when(){#do();}'-/who(979,84)mul(47,100){mul(56,886)where()mul(426,955)how()-(^/,when()~mul(5,654)()}*{{!}@&mul(397,386)what()where()$;mul(52,610)~what(967,284)];where()&from()mul(149,217)how()]what(694,413)+,;$/mul(808,101)why()mul(587,80) {:'who())when()mul(607,385) $(why()mul(368,757)how();mul(439,132)[how()}mul(211,816){~~'?when()why() /'mul(672,18){)+-{[],what()[mul(864,419)'>how()&&-mul(318,148)<from()<mul(10,526)>!mul(314,57)how()select()'[;/):>mul(722,602):~*[<do()where(){mul(219,344)what()when()$where()*%mul(134,909)/why()-$-do())$#&:?:mul(711,658)mul(841,476)]*~+]$mul(49,13)}[when()when(){%mul(384,180)from()[@-^where()}mul(983,543)mul(139,179)@where():when()/,when()(mul(659,253)' mul(62,501)how()[%when();>&select()who()mul(98,183)]&?-%$mul(408,996)&!<}&how()mul(406,286){,from()^]when()'mul(633,266)how())',where(){%mul(128,213)[why()don't()}{*@what(31,641) @}mul(732,47)what()+:+why(168,340){mul(119,206)*>']-%mul(924,311)how()}+mul(893,442)+{%what()where()@$@/}mul(445,325)who()when()!)[$)!+mul(804,553)~select()mul(980,422)?{[(}do()&[mul(385,988):what()%+when()]from()';]mul(865,805)from()'who()(who()]mul(77,141)from()$^mul(388,887)-!(!<):#where()mul(685,241)<what()}^<#mul(153,483)how()from()&how()'[mul(90,462)select(),<mul(604,556))mul(814,297)^select()@>mul(778,283)what()why()& ^,;mul(294,11)+;[mul(917/;~(:mul(730,865)^]-'when()-;mul(115,72) ~how()(+mul(457,940) %[@who()(what();-mul(610,656)when(279,723)'mul(519,892);when()^how()how(){{! )mul(702,416)mul(798,412)*?-mul(794,64)<:-,mul(676,525+%!@ #;mul(801,649){[]$mul(69,417)who()$from()when()($<(select(596,899)mul(261,685):][$&mul(929,616):what()how() ']mul(175,68))#mul(886,236}how()mul(674,816)!mul(452,370)+mul(816,632)'select()>%from()from()mul(195,244)(+(mul(791,10)}/from(300,146)'?when()mul(215,741)]where(103,872)mul(409,176)(*what()](from(863,305)who()why()mul(955,865{who()@#where()mul(297,379)how()mul(501,474)%when()?)>/(}}mul(630,973)#+how()who()+@mul(718,677)$ mul(332,719)@who()}-<{(]how()mul(378,679)
-}{mul(151,620)?;what()what()mul(460,159)mul(148,397)%select()how()what(378,143)]->-don't()mul(784!&from()why() [(mul(363,632){!+[#select(539,282)<+{}mul(194,454)mul(225,13)who(),~why()mul(470,396)select()'! 'select()>#mul(811,434)who()why()^$<mul(910,472)from()what()<#% <mul(5,983)}who())how(748,467))mul(430,119)+}/^?%//?mul(93,585)(mul(978,123)<{how()^!-,!mul(358,144) why()*?mul(284,926)~mul(153,398{#}what()from(776,381)[mul(438,896)mul(555,810)select()don't() 'select()when();!$who()&-mul(799,593)#from()!^+mul(607,126)*<&mul(109,706)select()$how(){@why()how(238,959)mul(62,344)]&*why()%select()mul(590,758);;+('why()why()~mul(767,411)mul(920,808)'$'who()/((when()mul(193,909))select();/!$ )@from()mul(113,906)what()(>@when()*$,mul(81,990~@why()*from()when(410,130)'who(){'do(),what(327,205)([/&mul(280,543)who()}from()mul(60,763) when()%from(),who()what()$@:mul(358,649)~;^>(}$#when() mul(215,541),{#&mul(146,968)!select()/mul(889,341){{,:from():how(),who()mul(760,75)}$^]*@mul(879,12)select()why()from()#mul(448,862)what()(why()how()mul(861select(22,313)where()+]&select()#where()how(){>mul(307,995)&}^]who(){&/>where()mul(215,994)/)@!<!,!#:mul(36,142)from(935,213)why():&&,(mul(990,246)?/why(){>how()mul(237,136)why(414,279)select(827,276)~#'when()+mul(158,693)~>&-mul(361,108)}don't()mul(183,33',mul(544,765)%from()how(437,534)mul(40,513)[what()select();>?mul(857,435)^from()!&mul(847,995)$(+mul(29,426)select()who()mul(610,544);~?:%~%what()mul(198,813)where()from() !^'*mul(957)(how()'who()'do()>!($?~when();<}mul'{ 'select()mul(72,475)^how()mul(664,323) mul(388,133)how()where()select()from()>{,what()>mul(911,218):$ how()]how()from()mul(170,205)~why()><{+mul(120,824)why()#* ,mul(220>!+{[[%]mul(831,625)&>when()where() mul(3,727)]when(263,910)&^?@/#mul(85,435)where()why()*#<]mul(752,444)when()how()^>][[&]mul(286{}*+@>:mul(416,788)/mul(830-{~where(),mul(790,584)?&from():$}mul(794,888)mul(960,792)mul(590,868)!;'mul(872,703)select()>mul(99,284)-$/:]don't()select(){]how()when()when()*mul(602,996)mul(470,918)how(509,287)@mul(426,545)&where()#}who(){$who())+don't()>(why()>]what()#mul(163,643)},mul(95,404)who()^~who()[ mul(599,651)why()mul(130,47)mul(543,322)mul(173,701)[why()from()?!;what()*+mul(414,739)@+&what()]~who()where()@mul(443,727)mul(478,904)mul(880,17)why(),who()<mul(826,227)*select()#how()mul(490,914)who()what()$what(){+ +:&mul(842,99)mul(132,144)how()mul(785,124) mul(676,518)@)@}>mul(357,496)#@;from()*,when(328,222)!-mul(799,869)from(867,967)^mul(132,113)&%&$}who()(^<mul(892,397)mul(669,317)mul(894,926)#^,mul(851,343);$mul(157,514)mul(538,421)@$[who()!where()mul(224,863)@how(225,54)(~how()#~:%{mul(755,544);!?%?-%&#mul(624,714))what(){?)<:!mul(652,38)from()$mul(190,724)how()where()*}^]+mul(16,4)how()<{+'%mul(519,858)~/when()}{mul(652,971)<^how()when()};mul(92,342):)why()/-$:#;don't()?@where(678,302);,^)~+mul(440,532)from()why()*?'don't() mul(101,677) ]^don't();~ ,mul(842,709):,#why()(}mul(948select()!-##<[)^ don't();+&( 'when()when()]+mul(471,505)select()[+,how()don't()how()mul(20,646)(select();@where()who()select()}what()mul(61,584)~-%^:(when()mul(11,52),(mul(795,394)mul(809,781)how(){>{$mul(810,797)who()#;select()]#mul(428,687)
-select()%mul(286,410)!where()%:>)$ ;don't()from()] :!~mul(319,75)how()}!what()where()mul(43,406):!-}]&%select()#mul(312,645) mul(695,720)(do()}#mul(496,384)mul(190,370)(who()(select()>+mul(153,623)-^&mul(238,960)why()]why()mul(247,581)@+when()'&mul(565,777)[>how()who()&@mul(376,862))<+mul(229,825);,^what():)what()%why(722,136))mul(934,927)select()from()mul(792,440)/+,)}?mul(515,876)mul(936,141),& why()--&when()mul(244,349)#<&}mul(856,81)%?mul(560,911)/mul(398,319)how()how(564,789)who()'mul(866,349)/#(mul(875,723)select(357,394)({mul(548,679)mul(104,853)(what()mul(125,84)!#what()'> when(550,330)}mul(345,207)where()}who()#(:;;mul(79,593), select(98,514)@mul(662,325) ~from(),:mul(878,614)+,}from()from()mul(464,320&&mul(423,635)?{<select()@who()where() mul(323,956)^'/@(mul(537,948)~how()mul(980,390)?~mul(123,655)*%from()where(833,844)from()what()&;*'mul(458,423)mul(774,946)mul(120,908)what(){mul(468,813)mul(8,158)(~-}@'<how();mul(457,21;who()?<$>mul(320,565)what()mul(238,226)*mul(863 [[#}where()~mul(688,955)!+:~?{mul(739,464){!who()$what()^?/what()mul(732,118)/@select(),@'*mul(413,443),<mul(783,259) mul(779,221)@from()mul(718,583)-@@{/;{mul(216,681)(:mul(814,747)'@^mul(984,11)]!-/where()&(mul(137,530)/&@!mul(299,515)#@^:~where()from()/(]mul(278,465)how()-:select()why()mul(369,208)['-mul(296%'from()what()what()@:'how()from()mul(839,265)!! -%how()who()%mul^mul(340,204)-:from()?,mul(786,123)^^#who()&#what()how()from()mul(869,187) %:<mul(558,49&$}don't()]*from()('{where()]mul(230,624)mul(508,826)!)mul(957,840))do()what()!{when()*<%why()mul(851,318)^<&&<,{?mul(230,564):]mul(489,169)mul(537,637)(mul(150,412)/mul(161,371)^~select()who()mul(226,926)what()[#[]mul(940,139)from()where()mul(841,796)>&&mul(980,951)how(){%mul(260,442):}from(935,941)$&from()do()why(),} 'mul(591,725)/(&what()>,'where()mul(770,486)mul(723,880)/what()mul(848,827)(+)mul(272,246)why()*select()~select()[don't()mul(534,655)~mul(430,122)*>'select()mul(813,601)]}where()>' why()mul(98,262)'<~ @how()why()mul(685,32)(mul(65,759)>*'#select()why()where()(mul(551,723)what();/why()/~where()mul(6,797)}who()when()when()'from()mul(133,463)+*;/!~select()how(930,545)+(mul(967,195)>-}+mul(608,974) @?from()@mul(796,430)[<$!mul(245,523))!when())%mul(640,721)when()&what()[&from() (mul(153,717)mul(269,270)*-,mul(295;when()];~$,#>^mul(502,652)-mul(3,88)}mul(741,952)$$how()[{*select())mul(613,551){^{what(384,722):when()-mul(853,266)what()+%@]*;mul(855,650)%mul(752:from()~,mul(676,939) -~**][&@)do()mul(491,582))/$%from()]mul(270,704),)mul, %(!,:;>}@mul(724,772)from()-mul(274,870)who(848,669)why();;~select()!mul(23,535)mul(162,588)-{select():[#,when()mul(701,961)&,][](>!mul(825,825)@%how()how()mul(486who()&mul(95,98)who()*-($':/;mul(329?mul(417,503)$!how()(!-+~,mul(495,776)why(724,71)(*%^<;??+#!/usr/bin/perl%<}'%from()?;;-mul(91,789)
-mul(41,601)<from()#/mul(676,921)}>-,from()mul(390,408)+~{where()when(){select()select(207,694)how()!mul(245,442){;?-mul(863,364)!+$&]$from()mul(313,947)]when()from()[mul(274,692};!;<$mul(253,339)select()@!mul(429,753)where()()where()who()where(243,435)!;?select()mul(187,466)mul(331,243)]who()from()don't()how(){from()from(757,974)$~,*how();mul(499,713)+~$!@(%>don't()mul(70,881)mul(513,263){&how()when(137,964)(&where()}!mul(189,511)><%mul(650,393)when()mul(858,491)[/-why()when(781,493)who()select()*,mul!(*}$;what()%mul(431,380)(what()](select()~do():^what()+;{what()mul(429,988)~where()select()mul(497,350)+why()%[&,)mul}]%where(389,283)select()%&?/who()what()mul(273,271)}mul(879,539)who(228,23)how()mul(715,626)>mul(629,602)who()!select()select()&~mul(590,77)>^#what(31,403)+$why())mul(270,509)!':;>mul(426,135)mul(362,542)who()what()?@[&;mul(129,79)];when()from()select() :mul(264,993)what()#$from(390,613)^!when(433,922)mul(997,92) when()(#?what()why(426,539)@mul(596,18)>;+()>{select()/mul(961,562))>!what()mul(53,801)select(),~how()mul(707,183):when()/mul(567,603){ ;why()who()who()mul(440,597)(':where()mul(530,863);who()!@-where()(+from()mul(44,871)*why()[;who()~{]where()mul(119,154)'?&%{]mul(757,502)#where(),]~$#mul(414,48)^why()how()]+(+}where()mul(487,649)-*when()[mul(631,838)-{(+mul(146,279)$from()/$*&how():mul(970,207)why()mul(396,214)?[(:mul(424,756)$ ;%[$how())mulwhat()%what()-who()$mul(696,505)mul(236,416)<~from()*why()?^mul(907,707)^ *how(6,966)@}from()mulfrom()mul(97,83)select()mul(942,896)-%&{,,+]>mul(267%from()~who(336,797)({;(how()?mul(885,741)[<how()mul(288,465)how()};<(; / mul(375,330)$[>when()mul(833,189)]why()who(138,936)%'@don't()$how()%where()<mul(700,62)?mul(237,777)+~/where()#;when()+select(){mul(504,400)>::<?~mul(4,954)+-what()/: &%select()mul(610,32)who()mul(281,866))/)!-mul(666,646from()from()^ }mul(819,676)*^where()mul(74,246)(mul(524,407)/?#when()]+]mul(811,848);/mul(855,879!<mul(2,840)&what()how()#do()>where()mul(106,91)/(mul(6;mul(441,884)mul(463,230)?mul(237,734)mul(572,813)> %/$$%mul(610,884)'{-where(736,602)from(){,*mul(424,262)- ?mul(366,946)~#don't()(select()*<:$^<#mul(511,659)]mul(802,38)what()why(447,86)/>~>mul(601,794)[#+( @mul(394,968)>>^:from()?{mul(364,986)>&*mul(691,417)&#mul(68,226)'who()when()#+ !select()  mul(851,397)where() do()from(637,960)how()!#how()#why(146,671)/#^mul(265,541),!}[/[!who()where()&mul(457,802);when()where()select()select()+;mul(264,706)&>+select()*^ (<mul(53,507)}mul(477,658)]mul(435,519?,mul(558,9)&select()select(484,9)mul(675,667)^&{'>~/$;mul(811,344)don't()where()~;~;why()@from()[?mul(13,492)] why()%*mul(76,124)don't();mul(318,609)'^> ~&+mul(869,893]mul(302,589)^&;}{'who(){select()why()mul(325,751)what()}mul(501,753)(select()mul(168,644)~&@select(),,;{where()>mul(849,471)mul(44,718) $# where()'^;mul(808,702)mul(499,97))how(){!+ ;mul(481,317)mul(483,257)/*}*<++mul(786,722)how()from(891,528)+from():*who()^-%mul(23,960)do()$?why()^{[>why() mul(633,719~>'who(394,112)((&what()how(854,140)'<mul(731,50)what()*%when() [mul(359,931)when():select()mul:when()$<^mul(680,63)select(842,681)#}mul(845,5)!how()#/]},[why()[mul(742,551)$/>}select()}, )[mul(399,545)?how(72,439)mul(855,649)/)>&!~!'#mul(110,462)
-!?(mul(265,787)+<what():>:)~^}mul(205,31)/when()mul(528,224)) ,mul(899,147)why()who()?how()$>)mul];mul(852,941)!+,select()}[mul(916,544)what()<why()@%what()mul(770,72)$,:mul(560,323)>-why()($~?*mul(51,747)@&who()~:when()mul]mul(268,890)mul(101,501)+mul(199,767)why()?mul(951:&how()$what(398,327) mul(176,615)}why(){mul(817,838)(?from()}[-)?mul(26,676)mul(898,603)mul(15,240)why(734,285)]{-]mul(113,376)what()^-?~$$+>,mul(841,756)%what()+mul(639,208){^[how())mul(607,29)from();%&&mul(345,982)from()-how(205,755)mul(697,484'*]when()[#&mul(86,157)%}mul(154,337)#mul(440,507)how()(when(999,893);@,%mul(83,632)#from(),*{~#::do()[[mul(295,299)(<<&what()&],$mul(702,763))@what()]mul(141,130)({~),!$?mul(622,741)what(13,58),mul(464~@mul(974,845)<when(),^,mul(875,295)^[-;+~#)mul(806,919)}}from()<[from()from()when():why()mul(799,911)^mul(913,634)}how()-mul(591,24)mul(359,310)mul(906,64)]%mul(659,147)][(mul(373,147#'why()from()}mul(211,965);){how()-((when(),{mul(822,799)%who()<when()$(@when()mul(181,802)??mul(477,726)%! (-mul(481,480)[ }:+;who()mul(996,97)}what()'<mul(755,440)$when()(mul,when(85,298)mul(177,673)$'!?*%&/)@mul(282,403)do() [when(444,224)why()!;,mul(97,283)where()>&$?mul(753,695) +mul(455,220)mul(427,37)-when()$-!$^select()&do()how()()mul(353,281)(why()<*~!%^/mul(215,125)^%where()%)don't()>)+select()[who()(select()+mul(911,752)&## ~$mul(927,607)[what(){>what())where()who()why(){don't()*/from(492,684)mul(620 ]]$[mul(839,491):^how()why()&)){'mul(759,591)don't()who()$why()%select()& mul(309,400)don't();mul(584,565)}why(311,180)where()mul(623,380)what()%-)[?~mul(251,642)mul(750,269)$who()mul(934,569)mul(741,113)-,select()select()mul(292,359)how()'['$mul(211,521)@mul(208,443)}]&mul(548,373)-^(*>who()>select()(mul(873,204)'+mul(619,852)*from()why()%what()<*/where()@mul(408,49)from()$<:&$when()mul(340,655)$why()'mul(447,708)from()mul(646,904)^/when()<who()mul(353,748):%when()@what(),^@mul(925,994) &{/what()mul(195,82)/who();#?,mul(623,70)$mul(109,589)!-mul(401,995)@)when()-(&(mul(406,46)(where()}how()how()what()when()#![mul(731,345))~/mul(586,173)$@where()#mul(864,801)!who()*~:mul(434,634)where()/<^+'/)mul(22,17):when() ~#!when(799,561)-how()mul(106,619)when()from()how()-)$>mul(816,508)(!-select(943,717)!what()mul(546,199)-where()-mul(793,322)#(where() ?mul(292,741)from()?<]][why()don't()@%^why()%how()!(#;mul(793,313),/ %where()/}<mul(63,121)when(651,88)who()mul(760,991)]how()from(), &^mul(302,126)<+/[()why()]':do()?}^how()]?mul(427,933)mul(143,664)#*$when()mul(246,967)]why()(*#/mul(230,650))$mul(929,202)]#('}:how()mul(27,150)]@,^^]mul!when()@{where()where()what()@,'mul(773,395)[!what()mul(425,697)'#{}$&>why():mul(637,826)-(mul(74,129)mul(431,516)+:]} don't()(: where()mul(372,520)why()$'what()!how()mul#{from(215,258)mul(793,788)from()'^%,}mul(236,982)who()mul(298,691)+~?mul(383,423)*select()select()^%mul(876,594 who()+ #mul(892,534)* ~@@when()%>}-mul(785,611)
-]where()mul(453,180)??>mul(263,301)#why()^mul(151,14)what()~%mul(657&/when()!(select()/mul(753,239)mul(981,751)-*:&<]<]:mul(117,560)%from();(#/}<$!mul(573,188)when()from()~'mul(696,782)',~what()select()]'#:mul(165,795)mul(815,773)when()mul(355,787){how())mul(839,915)'how(){when(),{]from(179,379)mul(573,783)!select()%don't()mul(23,703)&%$>%^who() $mul(930,791%+]from()where()#( <select()mul(263,220)$%]'*mul(481,91)mul(135,195)from()-[;$]mul(785,85)where()mul>[who()<@$mul(688,337)>)%mul(185,919)when())how()$-who()!mul(612,519)$]from()^mul(454'who()<(,mul(660,98)where()mul(607,293)/^;what()#~mul(70,4)-]mul(739,90)}*-^~,^when()do()%how()*<&;&?mul(818,372)mul(644,613)from()'/don't()^&~:where()when()mul(843,685)mul(679,496)*'when(168,153)mul(903,926)mul(446,647)from()mul(451,869)how()how()*when()/^mul(279,916)/*#what()]~why()mul(641,725)?;;how():$mul(744,915)^$ ?^,where()mul(276,195)mul(847,770)!$from()]select()#?!mul(496,322)mul(925,216)mul(808,922)$mul(949,473)[%?)mul(226,611)where()where():?what()}mul(215(~[@how()'mul(641,347)why()where()mul(170,732select()mul(305,387)?($#&mul(115,427)',*mul(929,465)'%,mul(987,858)-who(187,832){#[}why(703,805)mul(764,554) where();[>mul(187,674):+from()+*mul(295,896)',why()?$mul(851,833)mul(450,885)mul(955,192)~when(136,741)&{!(who()#$mul(367,19)+$when()!)*?#who()do()mul(719,104)mul(392,23)when()}?from()from(),+-(mul(529,406)]who()how(74,538)!>'~do()#how();where()>'select()!!mul(980,426)[mul(606,66)^~;}@mul(278,314)!(~?]#do()~where(865,279)!?mul(807,354)*from()from()^what()&when()'*mul(787,806)who()~*why()mul(872,129)}#~}who()who()mul(30,999){why()<when()from()mul(501,45)mul(30,649)^why()}mul(839,423)[from(379,233);what();*mul(663,696)~?<mul(685,891)'why()*mul(82,917)/do()?&#what()+#{{- mul(813,471)from()mul(674,260)<)mul(573,634)#]>%where()?*,}mul(801,561)where()who()'mul(400,710)what(){'!why()how()mul(597,843)@{when()when()%&]+[mul(92,957)how()$#](mul(4,791)mul(163,259)what(954,59)what(66,63)select(){[?mul(881,51)@{ *}@]mul(176,766)~&where()!don't()how()&(~]mul(381,70)&how()#~mul(559,369)%['where()from()how()mul(961,670)$*:when()%{what()mul(299,527)mul(492,254)mul(893,260)how()&'who())/mul(706,26) select(){)how()}^&[?mul(271,253)#}how()*do()'<$*mul(116,227)-from(){(mul(39,960)why()<select()] how(),select()how()mul(261,181)who()(;what(655,267)mul(797,645);~} ;!why()^+/mul(105,97);,mul(760,900),where()why():[(how()select()+mul(259,341);why()/ ;@how()*[<mul(310,851)when()>select()'$-))^mul(151,627):mul(361,566) &;,don't()!where(513,865)~where()##%@{mul(3,517)<where()%,when()!'%mul(845,113)[&;[select()!mul(435,7)(} ~;where();where()don't()*><+why(897,226)%^how()mul(386,769)how()+/^%<}**@mul(813,568)<:~why()select()%#mul(570,763)<$where()/@why(){;:/mul(839,305)how(219,584),select():$mul(307,561)mul(35,580)(from()?%mul(538,264)[do()~}#@>how()who()}{who()mul(508,52){?/,mul(670,840)~?/-</select()(mul(972,173)-~&where()who(585,296)[from()}@mul(316,223)}}from()#@";
@@ -0,0 +1,114 @@
+use aoc_utils::{Example, ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Vec<char>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("18").part2("9")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(parsed: &Self::Input) -> i64 {
+        let slices: Vec<&[char]> = parsed.iter().map(|v| &v[..]).collect();
+        xmas_search(&slices)
+    }
+
+    fn part2(parsed: &Self::Input) -> i64 {
+        let slices: Vec<&[char]> = parsed.iter().map(|v| &v[..]).collect();
+        x_mas_search(&slices)
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Vec<char>> {
+    input.split("\n").map(|x| x.chars().collect()).collect()
+}
+
+fn xmas_search(input: &[&[char]]) -> i64 {
+    let mut count: i64 = 0;
+    for x in 0..input.len() {
+        for y in 0..input[x].len() {
+            for x_dir in [-1, 0, 1] {
+                for y_dir in [-1, 0, 1] {
+                    if get_beam::<4>(input, x, y, x_dir, y_dir) == ['X', 'M', 'A', 'S'] {
+                        count += 1
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+fn x_mas_search(input: &[&[char]]) -> i64 {
+    let mut count: i64 = 0;
+    for x in 1..input.len() {
+        for y in 1..input[x].len() {
+            if get_safe(input, x, y) != 'A' {
+                continue;
+            }
+            let (a, b, c, d) = (
+                get_safe(input, x - 1, y - 1),
+                get_safe(input, x + 1, y - 1),
+                get_safe(input, x - 1, y + 1),
+                get_safe(input, x + 1, y + 1),
+            );
+            if !((a == 'M' && d == 'S') || (a == 'S' && d == 'M')) {
+                continue;
+            }
+
+            if !((b == 'M' && c == 'S') || (b == 'S' && c == 'M')) {
+                continue;
+            }
+
+            count += 1;
+        }
+    }
+    count
+}
+
+fn get_beam<const SIZE: usize>(
+    input: &[&[char]],
+    x_base: usize,
+    y_base: usize,
+    x_dir: isize,
+    y_dir: isize,
+) -> [char; SIZE] {
+    let mut beam = [' '; SIZE];
+    for (i, character) in beam.iter_mut().enumerate() {
+        *character = get_safe(
+            input,
+            (x_base as isize + i as isize * x_dir) as usize,
+            (y_base as isize + i as isize * y_dir) as usize,
+        );
+    }
+    beam
+}
+
+fn get_safe(input: &[&[char]], x: usize, y: usize) -> char {
+    if x < input.len() && y < input[x].len() {
+        input[x][y]
+    } else {
+        ' '
+    }
+}
+
+const TEST_INPUT: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+aoc_utils::example_tests!(Day04);
@@ -1,35 +1,61 @@
-use std::fs;
-
-fn main() {
-    let input_with_trailing =
-        fs::read_to_string("input.txt").expect("Should have been able to read the file");
-    let input = input_with_trailing.trim_end();
-    let (rules, data) = parse_input(input);
-    let (correct_data, incorrect_data) = sort_data(&rules, &data);
-    let result1 = sum_middle_pages(&correct_data);
-    println!("Result 1: {result1}");
-
-    let fixed_incorrect_data = fix_incorrect_data(&rules, incorrect_data);
-    let slices: Vec<&Vec<usize>> = fixed_incorrect_data.iter().collect();
-    let result2 = sum_middle_pages(&slices);
-    println!("Result 2: {result2}");
+use aoc_utils::{Example, ParseError, Solution, parse};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<(usize, usize)>, Vec<Vec<usize>>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("143").part2("123")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((rules, data): &Self::Input) -> usize {
+        let (correct_data, _) = sort_data(rules, data);
+        sum_middle_pages(&correct_data)
+    }
+
+    fn part2((rules, data): &Self::Input) -> usize {
+        let (_, incorrect_data) = sort_data(rules, data);
+        let fixed_incorrect_data = fix_incorrect_data(rules, incorrect_data);
+        let slices: Vec<&Vec<usize>> = fixed_incorrect_data.iter().collect();
+        sum_middle_pages(&slices)
+    }
 }
 
-fn parse_input(input: &str) -> (Vec<(usize, usize)>, Vec<Vec<usize>>) {
-    let (rules_str, data_str) = input.split_once("\n\n").unwrap();
-    let rules: Vec<(usize, usize)> = rules_str
+type Rules = Vec<(usize, usize)>;
+
+/// Pages are looked up in arrays indexed by their number.
+const MAX_PAGES: usize = 100;
+
+fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<usize>>), ParseError> {
+    let (rules_str, data_str) = parse::section_pair(input)?;
+    let rules = rules_str
         .split("\n")
         .map(|x| {
-            let (a, b) = x.split_once("|").unwrap();
-            (a.parse::<usize>().unwrap(), b.parse::<usize>().unwrap())
+            let (a, b) = parse::split_once(x, "|")?;
+            Ok((
+                parse::number_in(a, 0..MAX_PAGES)?,
+                parse::number_in(b, 0..MAX_PAGES)?,
+            ))
         })
-        .collect();
+        .collect::<Result<_, ParseError>>()?;
 
-    let data: Vec<Vec<usize>> = data_str
+    let data = data_str
         .split("\n")
-        .map(|x| x.split(",").map(|n| n.parse::<usize>().unwrap()).collect())
-        .collect();
-    (rules, data)
+        .map(|line| {
+            line.split(',')
+                .map(|page| parse::number_in(page.trim(), 0..MAX_PAGES))
+                .collect()
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok((rules, data))
 }
 
 fn fix_incorrect_data(rules: &[(usize, usize)], data: Vec<&Vec<usize>>) -> Vec<Vec<usize>> {
@@ -40,7 +66,7 @@ fn fix_incorrect_data(rules: &[(usize, usize)], data: Vec<&Vec<usize>>) -> Vec<V
 
 fn fix_single_data(rules: &[(usize, usize)], mut data: Vec<usize>) -> Vec<usize> {
     while !verify_data(rules, &data) {
-        let mut number_indices = [usize::MAX; 100];
+        let mut number_indices = [usize::MAX; MAX_PAGES];
         data.iter()
             .enumerate()
             .for_each(|(i, n)| number_indices[*n] = i);
@@ -71,7 +97,7 @@ fn sort_data<'a>(
 }
 
 fn verify_data(rules: &[(usize, usize)], data: &[usize]) -> bool {
-    let mut number_indices = [usize::MAX; 100];
+    let mut number_indices = [usize::MAX; MAX_PAGES];
     data.iter()
         .enumerate()
         .for_each(|(i, n)| number_indices[*n] = i);
@@ -111,3 +137,5 @@ const TEST_INPUT: &str = "47|53
 75,97,47,61,53
 61,13,29
 97,13,75,29,47";
+
+aoc_utils::example_tests!(Day05);
@@ -1,8 +1,9 @@
+use aoc_utils::{Example, ParseError, Solution, parse};
 use rayon::prelude::*;
-use std::{collections::HashSet, fs};
+use std::collections::HashSet;
 
 #[derive(Clone)]
-struct MapData {
+pub struct MapData {
     obstacles: Vec<(usize, usize)>,
     start_position: (usize, usize),
     map_size: usize,
@@ -14,17 +15,32 @@ struct PathPoint {
     direction: (isize, isize),
 }
 
-fn main() {
-    let input_with_trailing =
-        fs::read_to_string("input.txt").expect("Should have been able to read the file");
-    let input = input_with_trailing.trim_end();
-    let data: MapData = parse_input(input);
-    let path = find_path(&data);
-    let path_unique_locations = HashSet::<(usize, usize)>::from_iter(path.clone());
-    let unique_count = path_unique_locations.len();
-    println!("Part one: {unique_count}");
-    let possible_loop_count = find_all_loops(&data, &path_unique_locations.into_iter().collect());
-    println!("Part two: {possible_loop_count}");
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = MapData;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("41").part2("6")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Input) -> usize {
+        let path = find_path(data);
+        HashSet::<(usize, usize)>::from_iter(path).len()
+    }
+
+    fn part2(data: &Self::Input) -> usize {
+        let path = find_path(data);
+        let path_unique_locations = HashSet::<(usize, usize)>::from_iter(path);
+        find_all_loops(data, &path_unique_locations.into_iter().collect())
+    }
 }
 
 fn find_path(data: &MapData) -> Vec<(usize, usize)> {
@@ -89,10 +105,8 @@ fn has_loop(data: &MapData) -> bool {
             position,
             direction,
         };
-        if visited_tiles[position.0][position.1] {
-            if path.contains(&path_point) {
-                return true;
-            }
+        if visited_tiles[position.0][position.1] && path.contains(&path_point) {
+            return true;
         }
         visited_tiles[position.0][position.1] = true;
         path.push(path_point);
@@ -109,40 +123,32 @@ fn can_move_to_next_tile(
         (position.0 as isize + direction.0) as usize,
         (position.1 as isize + direction.1) as usize,
     );
-    !data
-        .obstacles
-        .iter()
-        .any(|obstacle| *obstacle == next_position)
+    !data.obstacles.contains(&next_position)
 }
 
 fn rotate_vec2_right(current: (isize, isize)) -> (isize, isize) {
     (current.1, -current.0)
 }
 
-fn parse_input(input: &str) -> MapData {
+fn parse_input(input: &str) -> Result<MapData, ParseError> {
     let map_size = input.split("\n").count();
     let mut start_position = (0, 0);
     let mut obstacles = Vec::new();
-    input
-        .split("\n")
-        .collect::<Vec<_>>()
-        .into_iter()
-        .rev()
-        .enumerate()
-        .for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, char)| {
-                if char == '#' {
-                    obstacles.push((x, y))
-                } else if char == '^' {
-                    start_position = (x, y)
-                }
-            })
-        });
-    MapData {
+    for (y, line) in input.lines().rev().enumerate() {
+        for (x, char) in line.char_indices() {
+            match char {
+                '#' => obstacles.push((x, y)),
+                '^' => start_position = (x, y),
+                '.' => {}
+                _ => return Err(parse::unexpected_char(line, x, "one of `.#^`")),
+            }
+        }
+    }
+    Ok(MapData {
         obstacles,
         start_position,
         map_size,
-    }
+    })
 }
 
 const TEST_INPUT: &str = "....#.....
@@ -155,3 +161,5 @@ const TEST_INPUT: &str = "....#.....
 ........#.
 #.........
 ......#...";
+
+aoc_utils::example_tests!(Day06);
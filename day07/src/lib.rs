@@ -1,33 +1,44 @@
+use aoc_utils::{Example, ParseError, Solution, parse};
+
 #[derive(Debug)]
-struct Equation {
+pub struct Equation {
     result: i64,
     terms: Vec<i64>,
 }
 
-fn main() {
-    let input_with_trailing =
-        std::fs::read_to_string("input.txt").expect("Should have been able to read the file");
-    let input = input_with_trailing.trim_end();
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = ();
 
-    let equations = parse_input(input);
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("3749").part2("11387")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
 
-    let calibration_sum = total_calibration_sum(&equations, false);
-    println!("Calibration sum: {calibration_sum}");
-    let calibration_sum_2 = total_calibration_sum(&equations, true);
-    println!("Calibration sum 2: {calibration_sum_2}");
+    fn part1(equations: &Self::Input) -> i64 {
+        total_calibration_sum(equations, false)
+    }
+
+    fn part2(equations: &Self::Input) -> i64 {
+        total_calibration_sum(equations, true)
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Equation> {
+fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
     input
         .split("\n")
         .map(|line| {
-            let (left, right) = line.split_once(": ").unwrap();
-            let result = left.parse::<i64>().unwrap();
-            let terms = right
-                .split(" ")
-                .map(|num| num.parse::<i64>().unwrap())
-                .collect();
-            Equation { result, terms }
+            let (left, right) = parse::split_once(line, ": ")?;
+            let result = parse::number(left)?;
+            let terms = parse::whitespace_list(right)?;
+            Ok(Equation { result, terms })
         })
         .collect()
 }
@@ -47,8 +58,7 @@ fn total_calibration_sum(equations: &[Equation], concat: bool) -> i64 {
 
 fn can_compute(equation: &Equation, concat: bool) -> bool {
     let possibilities = compute_all_possibilities(&equation.terms, concat);
-    let can_compute = possibilities.iter().any(|x| *x == equation.result);
-    can_compute
+    possibilities.contains(&equation.result)
 }
 
 fn compute_all_possibilities(terms: &[i64], concat: bool) -> Vec<i64> {
@@ -89,3 +99,5 @@ const TEST_INPUT: &str = "190: 10 19
 192: 17 8 14
 21037: 9 7 18 13
 292: 11 6 16 20";
+
+aoc_utils::example_tests!(Day07);
@@ -1,24 +1,35 @@
+use aoc_utils::{Example, ParseError, Solution, Vector2i};
 use std::collections::{HashMap, HashSet};
-use vector2d::Vector2D;
 
 #[derive(Debug, PartialEq)]
-struct Antenna {
-    location: Vector2D<i64>,
+pub struct Antenna {
+    location: Vector2i,
     distances: Vec<Vec<usize>>,
 }
 
-fn main() {
-    let input_with_trailing =
-        std::fs::read_to_string("input.txt").expect("Should have been able to read the file");
-    let input = input_with_trailing.trim_end();
+pub struct Day08;
 
-    let (antennas, size) = parse_input(input);
+impl Solution for Day08 {
+    const DAY: u8 = 8;
 
-    let resonances = find_resonances(&antennas, size as i64);
-    println!("Resonances count: {resonances}");
+    type Input = (HashMap<char, Vec<Antenna>>, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
 
-    let resonances_2 = find_resonances_2(&antennas, size as i64);
-    println!("Resonances 2 count: {resonances_2}");
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("14").part2("34")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part1((antennas, size): &Self::Input) -> usize {
+        find_resonances(antennas, *size as i64)
+    }
+
+    fn part2((antennas, size): &Self::Input) -> usize {
+        find_resonances_2(antennas, *size as i64)
+    }
 }
 
 fn gcd(mut n: i64, mut m: i64) -> i64 {
@@ -34,10 +45,6 @@ fn gcd(mut n: i64, mut m: i64) -> i64 {
     n
 }
 
-fn mul_vector2d(n: i64, vec: Vector2D<i64>) -> Vector2D<i64> {
-    Vector2D::new(n * vec.x, n * vec.y)
-}
-
 fn find_resonances(all_antennas: &HashMap<char, Vec<Antenna>>, size: i64) -> usize {
     let mut resonance_points = HashSet::<(i64, i64)>::new();
 
@@ -75,7 +82,7 @@ fn find_resonances_2(all_antennas: &HashMap<char, Vec<Antenna>>, size: i64) -> u
                 let normalized_direction = direction / gcd(direction.x.abs(), direction.y.abs());
 
                 for n in -size * 2..size * 2 {
-                    let position = antenna1.location + mul_vector2d(n, normalized_direction);
+                    let position = antenna1.location + normalized_direction * n;
                     if !(0 <= position.x
                         && position.x < size
                         && 0 <= position.y
@@ -102,7 +109,7 @@ fn parse_input(input: &str) -> (HashMap<char, Vec<Antenna>>, usize) {
         .filter(|(_, _, char)| *char != '.')
         .for_each(|(x, y, char)| {
             let frequency = char;
-            let location = Vector2D::new(x as i64, y as i64);
+            let location = Vector2i::new(x as i64, y as i64);
             let distances = (0..size)
                 .map(|y_l| {
                     (0..size)
@@ -134,3 +141,5 @@ const TEST_INPUT: &str = "............
 .........A..
 ............
 ............";
+
+aoc_utils::example_tests!(Day08);
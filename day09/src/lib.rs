@@ -0,0 +1,120 @@
+use aoc_utils::{Example, ParseError, Solution, parse};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DataBlock {
+    Empty,
+    File(usize),
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<DataBlock>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("1928").part2("2858")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Input) -> usize {
+        let mut data = data.clone();
+        defrag_data(&mut data);
+        calculate_checksum(&data)
+    }
+
+    fn part2(data: &Self::Input) -> usize {
+        let mut data = data.clone();
+        defrag_files(&mut data);
+        calculate_checksum(&data)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<DataBlock>, ParseError> {
+    let sizes = input
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .ok_or_else(|| parse::unexpected_char(input, i, "a digit"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(sizes
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, size)| {
+            if i % 2 == 0 {
+                std::iter::repeat_n(DataBlock::File(i / 2), size as usize)
+            } else {
+                std::iter::repeat_n(DataBlock::Empty, size as usize)
+            }
+        })
+        .collect())
+}
+
+fn defrag_data(data: &mut [DataBlock]) {
+    for i in (0..data.len()).rev() {
+        if data[i] == DataBlock::Empty {
+            continue;
+        }
+        let next_available_empty_block = data.iter().position(|x| *x == DataBlock::Empty);
+        if let Some(found_index) = next_available_empty_block {
+            if found_index >= i {
+                break;
+            }
+            data.swap(i, found_index);
+        } else {
+            break;
+        }
+    }
+}
+
+fn defrag_files(data: &mut [DataBlock]) {
+    let last_file = data
+        .iter()
+        .filter_map(|block| match block {
+            DataBlock::File(index) => Some(*index),
+            DataBlock::Empty => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    for file_number in (0..=last_file).rev() {
+        let file = DataBlock::File(file_number);
+        let Some(file_index) = data.iter().position(|x| *x == file) else {
+            continue;
+        };
+        let file_size = data.iter().filter(|x| **x == file).count();
+        for i in 0..file_index {
+            if data[i..i + file_size]
+                .iter()
+                .all(|x| *x == DataBlock::Empty)
+            {
+                data[i..i + file_size].fill(file);
+                data[file_index..file_index + file_size].fill(DataBlock::Empty);
+                break;
+            }
+        }
+    }
+}
+
+fn calculate_checksum(data: &[DataBlock]) -> usize {
+    data.iter()
+        .enumerate()
+        .map(|(i, data)| {
+            if let DataBlock::File(index) = data {
+                i * index
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+const TEST_INPUT: &str = "2333133121414131402";
+
+aoc_utils::example_tests!(Day09);
@@ -1,44 +1,47 @@
-mod vector2;
-
+use aoc_utils::{Example, Grid, ParseError, Solution, Vector2i};
 use std::collections::HashSet;
-use vector2::Vector2i;
 
-struct HeightMap {
-    size: i64,
-    heights: Vec<Vec<usize>>,
+pub struct HeightMap {
+    heights: Grid<usize>,
 }
 
 impl HeightMap {
     fn get(&self, location: &Vector2i) -> usize {
-        if location.x < 0 || location.x >= self.size || location.y < 0 || location.y >= self.size {
-            return usize::MAX;
-        }
-        self.heights[location.x as usize][location.y as usize]
+        *self.heights.get(location)
     }
 
     fn get_trailheads(&self) -> Vec<Vector2i> {
         self.heights
-            .iter()
-            .enumerate()
-            .flat_map(|(x, ys)| ys.iter().enumerate().map(move |(y, h)| (x, y, h)))
-            .filter(|(_, _, h)| **h == 0)
-            .map(|(x, y, _)| Vector2i::new(x as i64, y as i64))
+            .coordinates()
+            .into_iter()
+            .filter(|coord| self.get(coord) == 0)
             .collect()
     }
 }
 
-fn main() {
-    let input_with_trailing =
-        std::fs::read_to_string("input.txt").expect("Should have been able to read the file");
-    let input = input_with_trailing.trim_end();
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = HeightMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("36").part2("81")];
 
-    let map = parse_input(input);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
 
-    let total_score = map_score(&map);
-    println!("Total score: {total_score}");
+    fn part1(map: &Self::Input) -> usize {
+        map_score(map)
+    }
 
-    let total_rating = map_rating(&map);
-    println!("Total rating: {total_rating}");
+    fn part2(map: &Self::Input) -> usize {
+        map_rating(map)
+    }
 }
 
 fn map_score(map: &HeightMap) -> usize {
@@ -72,7 +75,7 @@ fn trailhead_score(location: Vector2i, map: &HeightMap) -> usize {
             n_reachable += 1;
         }
 
-        for direction in DIRECTION_VECTORS {
+        for direction in Vector2i::DIRECTION_VECTORS {
             let new_position = explore_now + direction;
             let new_height = map.get(&new_position);
             if new_height == height + 1 && !explored.contains(&new_position) {
@@ -101,7 +104,7 @@ fn trailhead_rating(location: Vector2i, map: &HeightMap) -> usize {
             n_reachable += 1;
         }
 
-        for direction in DIRECTION_VECTORS {
+        for direction in Vector2i::DIRECTION_VECTORS {
             let new_position = *explore_now.last().unwrap() + direction;
             let new_height = map.get(&new_position);
             if new_height != height + 1 {
@@ -115,26 +118,13 @@ fn trailhead_rating(location: Vector2i, map: &HeightMap) -> usize {
     n_reachable
 }
 
-fn parse_input(input: &str) -> HeightMap {
-    let size = input.lines().count() as i64;
-    let heights = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|h| h.to_digit(10).unwrap() as usize)
-                .collect()
-        })
-        .collect();
-    HeightMap { size, heights }
+fn parse_input(input: &str) -> Result<HeightMap, ParseError> {
+    let (heights, _) = Grid::from_text(input, usize::MAX, "", |h| {
+        h.to_digit(10).map(|h| h as usize)
+    })?;
+    Ok(HeightMap { heights })
 }
 
-const DIRECTION_VECTORS: [Vector2i; 4] = [
-    Vector2i { x: 1, y: 0 },
-    Vector2i { x: -1, y: 0 },
-    Vector2i { x: 0, y: 1 },
-    Vector2i { x: 0, y: -1 },
-];
-
 const TEST_INPUT: &str = "89010123
 78121874
 87430965
@@ -143,3 +133,5 @@ const TEST_INPUT: &str = "89010123
 32019012
 01329801
 10456732";
+
+aoc_utils::example_tests!(Day10);
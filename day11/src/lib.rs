@@ -0,0 +1,183 @@
+use aoc_utils::{Example, ParseError, Solution, parse};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::num::NonZeroU64;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT)
+        .part1("55312")
+        .part2("65601038650482")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(stones: &Self::Input) -> u128 {
+        count_after_blinks(stones, 25).expect("25 blinks fit in a u128")
+    }
+
+    fn part2(stones: &Self::Input) -> u128 {
+        count_after_blinks(stones, 75).expect("75 blinks fit in a u128")
+    }
+}
+
+/// The stones as how many there are of each value, since their order never matters.
+#[derive(Debug, Clone)]
+pub struct Stones {
+    counts: HashMap<u128, u128>,
+    /// Counts are kept modulo this, if given, so any number of blinks fits.
+    modulus: Option<u128>,
+    blinks: usize,
+}
+
+/// A number got too big for a `u128` while blinking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// There are too many stones to count.
+    Count { blinks: usize },
+    /// The value engraved on a stone is too big to multiply.
+    Value { blinks: usize },
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Overflow::Count { blinks } => write!(
+                f,
+                "the number of stones no longer fits in a u128 after {blinks} blinks, count modulo a prime instead"
+            ),
+            Overflow::Value { blinks } => write!(
+                f,
+                "a stone's value no longer fits in a u128 after {blinks} blinks"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+impl Stones {
+    pub fn new(values: &[u64]) -> Stones {
+        let mut counts = HashMap::new();
+        for value in values {
+            *counts.entry(*value as u128).or_default() += 1;
+        }
+        Stones {
+            counts,
+            modulus: None,
+            blinks: 0,
+        }
+    }
+
+    /// Keeps the counts modulo `modulus` from now on. It must fit in a `u64`, so sums of two
+    /// counts can't overflow.
+    pub fn modulo(mut self, modulus: NonZeroU64) -> Stones {
+        let modulus = modulus.get() as u128;
+        self.counts.values_mut().for_each(|count| *count %= modulus);
+        self.modulus = Some(modulus);
+        self
+    }
+
+    pub fn blinks(&self) -> usize {
+        self.blinks
+    }
+
+    /// How many different values are engraved on the stones.
+    pub fn distinct_values(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The total number of stones, modulo the modulus if there is one.
+    pub fn total(&self) -> Result<u128, Overflow> {
+        self.counts
+            .values()
+            .try_fold(0, |total, count| self.add(total, *count, self.blinks))
+    }
+
+    pub fn blink(&mut self) -> Result<(), Overflow> {
+        let blinks = self.blinks + 1;
+        let mut counts: HashMap<u128, u128> = HashMap::with_capacity(self.counts.len());
+        for (value, count) in &self.counts {
+            let (first, second) = blink_stone(*value).ok_or(Overflow::Value { blinks })?;
+            for value in std::iter::once(first).chain(second) {
+                let entry = counts.entry(value).or_default();
+                *entry = self.add(*entry, *count, blinks)?;
+            }
+        }
+        self.counts = counts;
+        self.blinks = blinks;
+        Ok(())
+    }
+
+    fn add(&self, a: u128, b: u128, blinks: usize) -> Result<u128, Overflow> {
+        match self.modulus {
+            Some(modulus) => Ok((a + b) % modulus),
+            None => a.checked_add(b).ok_or(Overflow::Count { blinks }),
+        }
+    }
+}
+
+/// What a single stone turns into when blinking: one stone, or two when it splits. `None` when
+/// the stone's value gets too big to multiply.
+fn blink_stone(value: u128) -> Option<(u128, Option<u128>)> {
+    if value == 0 {
+        return Some((1, None));
+    }
+    let digits = value.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10_u128.pow(digits / 2);
+        Some((value / half, Some(value % half)))
+    } else {
+        Some((value.checked_mul(2024)?, None))
+    }
+}
+
+fn count_after_blinks(stones: &[u64], blinks: usize) -> Result<u128, Overflow> {
+    let mut stones = Stones::new(stones);
+    for _ in 0..blinks {
+        stones.blink()?;
+    }
+    stones.total()
+}
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse::whitespace_list(input)
+}
+
+const TEST_INPUT: &str = "125 17";
+
+aoc_utils::example_tests!(Day11);
+
+#[test]
+fn test_stones() {
+    let mut stones = Stones::new(&[125, 17]);
+    stones.blink().unwrap();
+    assert_eq!(stones.total(), Ok(3));
+    assert_eq!(stones.distinct_values(), 3);
+
+    let mut stones = Stones::new(&[0]);
+    while stones.blink().is_ok() {}
+    assert!(stones.blinks() > 75);
+
+    let modulus = NonZeroU64::new(1_000_000_007).unwrap();
+    let mut stones = Stones::new(&[125, 17]).modulo(modulus);
+    for _ in 0..1000 {
+        stones.blink().unwrap();
+    }
+    assert!(stones.total().unwrap() < 1_000_000_007);
+    assert!(stones.distinct_values() < 4000);
+
+    let mut stones = Stones::new(&[9_999_999_999_999_999_999]);
+    assert_eq!(stones.blink(), Ok(()));
+    assert_eq!(stones.total(), Ok(1));
+    assert_eq!(blink_stone(u128::MAX / 100), None);
+}
@@ -1,88 +1,29 @@
-mod vector2;
-
+use aoc_utils::{Example, Grid, ParseError, Solution, Vector2i};
 use std::collections::HashSet;
-use vector2::Vector2i;
 
-#[derive(Debug)]
-struct Grid<T> {
-    default: T,
-    size: usize,
-    values: Vec<Vec<T>>,
-}
+pub struct Day12;
 
-impl<T> Grid<T> {
-    fn get(&self, location: &Vector2i) -> &T {
-        if location.x < 0
-            || location.x >= self.size as i64
-            || location.y < 0
-            || location.y >= self.size as i64
-        {
-            return &self.default;
-        }
-        &self.values[location.x as usize][location.y as usize]
-    }
+impl Solution for Day12 {
+    const DAY: u8 = 12;
 
-    fn get_mut(&mut self, location: &Vector2i) -> &mut T {
-        if location.x < 0
-            || location.x >= self.size as i64
-            || location.y < 0
-            || location.y >= self.size as i64
-        {
-            return &mut self.default;
-        }
-        &mut self.values[location.x as usize][location.y as usize]
-    }
+    type Input = Vec<Grid<bool>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
 
-    fn set(&mut self, location: &Vector2i, value: T) -> bool {
-        if location.x < 0
-            || location.x >= self.size as i64
-            || location.y < 0
-            || location.y >= self.size as i64
-        {
-            return false;
-        }
-        self.values[location.x as usize][location.y as usize] = value;
-        true
-    }
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("772").part2("436")];
 
-    fn coordinates(&self) -> Vec<Vector2i> {
-        (0..self.size)
-            .flat_map(|y| {
-                (0..self.size).map(move |x| Vector2i {
-                    x: x as i64,
-                    y: y as i64,
-                })
-            })
-            .collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
-}
 
-impl<T: Clone> Grid<T> {
-    fn empty(size: usize, default: T) -> Grid<T> {
-        let values = (0..size)
-            .map(|_| (0..size).map(|_| default.clone()).collect())
-            .collect();
-
-        Grid::<T> {
-            default,
-            size,
-            values,
-        }
+    fn part1(maps: &Self::Input) -> usize {
+        count_cost(maps)
     }
-}
 
-fn main() {
-    let input_with_trailing =
-        std::fs::read_to_string("input.txt").expect("Should have been able to read the file");
-    let input = input_with_trailing.trim_end();
-
-    let maps = parse_input(input);
-
-    let cost = count_cost(&maps);
-    println!("Cost: {cost}");
-
-    let cost_with_discount = count_cost_with_discount(&maps);
-    println!("Cost with discount: {cost_with_discount}");
+    fn part2(maps: &Self::Input) -> usize {
+        count_cost_with_discount(maps)
+    }
 }
 
 fn count_cost(maps: &[Grid<bool>]) -> usize {
@@ -145,8 +86,8 @@ fn count_cost_with_discount(maps: &[Grid<bool>]) -> usize {
                 };
                 for direction in direction_map.get(&coord).clone() {
                     let scan_direction = direction.perpendicular();
-                    for i in 1..map.size {
-                        let next_scan_pos = coord + scan_direction * i as i64;
+                    for i in 1..map.size.x.max(map.size.y) {
+                        let next_scan_pos = coord + scan_direction * i;
                         if !direction_map.get(&(next_scan_pos)).contains(&direction) {
                             break;
                         }
@@ -167,26 +108,13 @@ fn count_cost_with_discount(maps: &[Grid<bool>]) -> usize {
         .sum()
 }
 
-fn parse_input(input: &str) -> Vec<Grid<bool>> {
-    let size = input.lines().count();
-    let values = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(move |(x, char)| char)
-                .collect()
-        })
-        .collect();
-    let map = Grid {
-        size,
-        values,
-        default: '/',
-    };
+fn parse_input(input: &str) -> Result<Vec<Grid<bool>>, ParseError> {
+    let (map, _) = Grid::from_text(input, '/', "", |c| c.is_ascii_uppercase().then_some(c))?;
+    let size = map.size;
     let mut used_coords: HashSet<Vector2i> = HashSet::new();
 
-    map.coordinates()
+    let regions = map
+        .coordinates()
         .into_iter()
         .filter_map(|coord| {
             let mut new_map = Grid::<bool>::empty(size, false);
@@ -195,8 +123,7 @@ fn parse_input(input: &str) -> Vec<Grid<bool>> {
             }
             let this_char = map.get(&coord);
             let mut to_explore: Vec<Vector2i> = vec![coord];
-            while !to_explore.is_empty() {
-                let explore_now = to_explore.pop().unwrap();
+            while let Some(explore_now) = to_explore.pop() {
                 new_map.values[explore_now.x as usize][explore_now.y as usize] = true;
                 to_explore.extend(Vector2i::DIRECTION_VECTORS.iter().filter_map(|dir| {
                     let next_coord = explore_now + *dir;
@@ -211,7 +138,8 @@ fn parse_input(input: &str) -> Vec<Grid<bool>> {
             }
             Some(new_map)
         })
-        .collect()
+        .collect();
+    Ok(regions)
 }
 
 const TEST_INPUT: &str = "OOOOO
@@ -219,3 +147,5 @@ OXOXO
 OOOOO
 OXOXO
 OOOOO";
+
+aoc_utils::example_tests!(Day12);
@@ -1,4 +1,7 @@
-struct Machine {
+use aoc_utils::{Example, ParseError, Solution, parse};
+
+#[derive(Clone)]
+pub struct Machine {
     x_1: i64,
     y_1: i64,
     x_2: i64,
@@ -7,20 +10,33 @@ struct Machine {
     y: i64,
 }
 
-fn main() {
-    let input_with_trailing =
-        std::fs::read_to_string("input.txt").expect("Should have been able to read the file");
-    let input = input_with_trailing.trim_end();
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("480")];
 
-    let mut machines = parse_input(input);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
 
-    let cost = calculate_prize(&machines);
-    println!("Cost: {}", cost);
+    fn part1(machines: &Self::Input) -> usize {
+        calculate_prize(machines)
+    }
 
-    add_offset(&mut machines);
-    let cost = calculate_prize(&machines);
-    println!("Cost 2: {}", cost);
+    fn part2(machines: &Self::Input) -> usize {
+        let mut machines = machines.clone();
+        add_offset(&mut machines);
+        calculate_prize(&machines)
+    }
 }
+
 fn add_offset(machines: &mut [Machine]) {
     machines.iter_mut().for_each(|machine| {
         machine.x += 10000000000000;
@@ -61,46 +77,23 @@ fn solution(machine: &Machine) -> Option<(i64, i64)> {
     Some((p, q))
 }
 
-fn parse_input(input: &str) -> Vec<Machine> {
-    input
-        .split("\n\n")
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    parse::sections(input)
+        .into_iter()
         .map(|machine| {
-            let lines: Vec<_> = machine.split("\n").collect();
-            let line1: Vec<_> = lines[0].split(" ").collect();
-            let line2: Vec<_> = lines[1].split(" ").collect();
-            let line3: Vec<_> = lines[2].split(" ").collect();
-
-            let x_1 = parse_integer_stuff(line1[2], "+", ",");
-            let y_1 = parse_integer_stuff(line1[3], "+", "\n");
-
-            let x_2 = parse_integer_stuff(line2[2], "+", ",");
-            let y_2 = parse_integer_stuff(line2[3], "+", "\n");
-
-            let x = parse_integer_stuff(line3[1], "=", ",");
-            let y = parse_integer_stuff(line3[2], "=", "\n");
-            Machine {
+            let [x_1, y_1, x_2, y_2, x, y] = parse::fixed_integers(machine)?;
+            Ok(Machine {
                 x_1,
                 y_1,
                 x_2,
                 y_2,
                 x,
                 y,
-            }
+            })
         })
         .collect()
 }
 
-fn parse_integer_stuff(word: &str, begin: &str, end: &str) -> i64 {
-    word.split(begin)
-        .nth(1)
-        .unwrap()
-        .split(end)
-        .next()
-        .unwrap()
-        .parse::<i64>()
-        .unwrap()
-}
-
 const TEST_INPUT: &str = "Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
@@ -116,3 +109,5 @@ Prize: X=7870, Y=6450
 Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279";
+
+aoc_utils::example_tests!(Day13);
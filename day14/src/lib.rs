@@ -0,0 +1,136 @@
+use aoc_utils::{Example, Grid, ParseError, Solution, Vector2i, parse};
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Vector2i,
+    velocity: Vector2i,
+}
+
+/// The robots, and the size of the room they wrap around in.
+#[derive(Debug, Clone)]
+pub struct Lobby {
+    robots: Vec<Robot>,
+    map_size: Vector2i,
+}
+
+/// Width and height of the room, which is smaller in the example.
+#[derive(Debug, Clone, Copy)]
+pub struct MapSize(pub Vector2i);
+
+impl Default for MapSize {
+    fn default() -> MapSize {
+        MapSize(Vector2i::new(101, 103))
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Lobby;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = MapSize;
+
+    const EXAMPLES: &'static [Example<MapSize>] =
+        &[Example::with_params(TEST_INPUT, MapSize(Vector2i { x: 11, y: 7 })).part1("12")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, MapSize::default())
+    }
+
+    fn parse_with(input: &str, map_size: &MapSize) -> Result<Self::Input, ParseError> {
+        parse_input(input, *map_size)
+    }
+
+    fn part1(lobby: &Self::Input) -> usize {
+        safety_score(lobby.robots.clone(), 100, lobby.map_size)
+    }
+
+    fn part2(lobby: &Self::Input) -> usize {
+        find_christmas_tree(lobby.robots.clone(), lobby.map_size)
+    }
+}
+
+fn find_christmas_tree(mut robots: Vec<Robot>, map_size: Vector2i) -> usize {
+    let mut time = 0;
+    loop {
+        time += 1;
+        robots
+            .iter_mut()
+            .for_each(|robot| advance_time(robot, 1, map_size));
+        if render(&robots, map_size).contains("XXXXXXXX") {
+            return time;
+        }
+    }
+}
+
+fn safety_score(mut robots: Vec<Robot>, time: usize, map_size: Vector2i) -> usize {
+    robots
+        .iter_mut()
+        .for_each(|robot| advance_time(robot, time, map_size));
+    let mut quadrants_count = [0; 4];
+    robots.iter().for_each(|robot| {
+        let left = robot.position.x < (map_size.x / 2);
+        let right = robot.position.x > (map_size.x / 2);
+        let top = robot.position.y < (map_size.y / 2);
+        let bottom = robot.position.y > (map_size.y / 2);
+        if left {
+            if top {
+                quadrants_count[0] += 1;
+            } else if bottom {
+                quadrants_count[1] += 1;
+            }
+        } else if right {
+            if top {
+                quadrants_count[2] += 1;
+            } else if bottom {
+                quadrants_count[3] += 1;
+            }
+        }
+    });
+
+    quadrants_count.iter().product()
+}
+
+fn advance_time(robot: &mut Robot, time: usize, map_size: Vector2i) {
+    let new_position = robot.position + robot.velocity * time as i64;
+    robot.position = new_position.modulo(map_size);
+}
+
+fn render(robots: &[Robot], map_size: Vector2i) -> String {
+    Grid::empty(map_size, ())
+        .render(|_| ' ')
+        .mark(robots.iter().map(|robot| &robot.position), 'X')
+        .to_string()
+}
+
+fn parse_input(input: &str, MapSize(map_size): MapSize) -> Result<Lobby, ParseError> {
+    let robots = input
+        .lines()
+        .map(|line| {
+            let [p_x, p_y, v_x, v_y] = parse::fixed_integers(line)?;
+            Ok(Robot {
+                position: Vector2i::new(p_x, p_y),
+                velocity: Vector2i::new(v_x, v_y),
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok(Lobby { robots, map_size })
+}
+
+const TEST_INPUT: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+aoc_utils::example_tests!(Day14);
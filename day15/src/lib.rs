@@ -0,0 +1,83 @@
+use aoc_utils::{Example, ParseError, Solution, Vector2i, parse};
+
+mod part1;
+mod part2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (part1::Warehouse, part2::Warehouse);
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(TEST_INPUT).part1("10092").part2("9021"),
+        Example::new(TEST_INPUT2).part2("618"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((part1::parse_input(input)?, part2::parse_input(input)?))
+    }
+
+    fn part1((warehouse, _): &Self::Input) -> i64 {
+        part1::coordinate_sum(warehouse)
+    }
+
+    fn part2((_, warehouse): &Self::Input) -> i64 {
+        part2::coordinate_sum(warehouse)
+    }
+}
+
+/// The robot's moves, which may be spread over several lines.
+fn parse_moves(moves: &str) -> Result<Vec<Vector2i>, ParseError> {
+    let mut directions = Vec::new();
+    for line in moves.lines() {
+        for (i, c) in line.char_indices() {
+            directions.push(match c {
+                '^' => Vector2i::new(0, -1),
+                'v' => Vector2i::new(0, 1),
+                '>' => Vector2i::new(1, 0),
+                '<' => Vector2i::new(-1, 0),
+                _ => return Err(parse::unexpected_char(line, i, "one of `^v<>`")),
+            });
+        }
+    }
+    Ok(directions)
+}
+
+const TEST_INPUT: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+const TEST_INPUT2: &str = "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+aoc_utils::example_tests!(Day15);
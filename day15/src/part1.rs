@@ -0,0 +1,72 @@
+use crate::parse_moves;
+use aoc_utils::{Grid, ParseError, Vector2i, parse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarehouseSpace {
+    Empty,
+    Box,
+    Wall,
+}
+
+pub type Warehouse = (Grid<WarehouseSpace>, Vector2i, Vec<Vector2i>);
+
+pub fn coordinate_sum(warehouse: &Warehouse) -> i64 {
+    let (mut map, mut position, mut instructions) = warehouse.clone();
+    advance(&mut map, &mut position, &mut instructions);
+    gps_coordinates(&map)
+}
+
+fn gps_coordinates(map: &Grid<WarehouseSpace>) -> i64 {
+    map.coordinates()
+        .into_iter()
+        .map(|coord| {
+            if *map.get(&coord) == WarehouseSpace::Box {
+                100 * coord.y + coord.x
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn advance(
+    map: &mut Grid<WarehouseSpace>,
+    position: &mut Vector2i,
+    instructions: &mut Vec<Vector2i>,
+) {
+    while !instructions.is_empty() {
+        let instruction = instructions.remove(0);
+        let start_position = *position + instruction;
+        let mut scanning_position = *position;
+        let mut moves_box = false;
+        loop {
+            scanning_position = scanning_position + instruction;
+            match map.get(&scanning_position) {
+                WarehouseSpace::Empty => {
+                    if moves_box {
+                        map.set(&start_position, WarehouseSpace::Empty);
+                        map.set(&scanning_position, WarehouseSpace::Box);
+                    }
+                    *position = start_position;
+                    break;
+                }
+                WarehouseSpace::Box => moves_box = true,
+                WarehouseSpace::Wall => break,
+            }
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Warehouse, ParseError> {
+    let (map, moves_str) = parse::section_pair(input)?;
+    let (grid, markers) = Grid::from_text(map, WarehouseSpace::Wall, "@", |c| match c {
+        '#' => Some(WarehouseSpace::Wall),
+        '.' | '@' => Some(WarehouseSpace::Empty),
+        'O' => Some(WarehouseSpace::Box),
+        _ => None,
+    })?;
+    let robot_position = markers.single('@')?;
+
+    let moves = parse_moves(moves_str)?;
+    Ok((grid, robot_position, moves))
+}
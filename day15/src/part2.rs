@@ -0,0 +1,139 @@
+use crate::parse_moves;
+use aoc_utils::{Grid, ParseError, Vector2i, parse};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarehouseSpace {
+    Empty,
+    LeftBox,
+    RightBox,
+    Wall,
+}
+
+pub type Warehouse = (Grid<WarehouseSpace>, Vector2i, Vec<Vector2i>);
+
+pub fn coordinate_sum(warehouse: &Warehouse) -> i64 {
+    let (mut map, mut position, mut instructions) = warehouse.clone();
+    advance(&mut map, &mut position, &mut instructions);
+    gps_coordinates(&map)
+}
+
+fn gps_coordinates(map: &Grid<WarehouseSpace>) -> i64 {
+    map.coordinates()
+        .into_iter()
+        .map(|coord| {
+            if *map.get(&coord) == WarehouseSpace::LeftBox {
+                100 * coord.y + coord.x
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn advance(
+    map: &mut Grid<WarehouseSpace>,
+    position: &mut Vector2i,
+    instructions: &mut Vec<Vector2i>,
+) {
+    while !instructions.is_empty() {
+        let instruction = instructions.remove(0);
+        let target_position = *position + instruction;
+
+        let mut to_move: HashSet<Vector2i> = HashSet::new();
+        to_move.insert(target_position);
+
+        let mut can_move = true;
+        let mut to_check: Vec<Vector2i> = vec![target_position];
+
+        while let Some(check_now) = to_check.pop() {
+            let dependent_positions = match map.get(&check_now) {
+                WarehouseSpace::LeftBox => {
+                    if instruction.y != 0 {
+                        vec![check_now + instruction, check_now + Vector2i::new(1, 0)]
+                    } else {
+                        vec![check_now + instruction]
+                    }
+                }
+                WarehouseSpace::RightBox => {
+                    if instruction.y != 0 {
+                        vec![check_now + instruction, check_now + Vector2i::new(-1, 0)]
+                    } else {
+                        vec![check_now + instruction]
+                    }
+                }
+                WarehouseSpace::Empty => {
+                    to_move.clear();
+                    break;
+                }
+                WarehouseSpace::Wall => {
+                    can_move = false;
+                    break;
+                }
+            };
+
+            for dep_pos in dependent_positions {
+                match map.get(&dep_pos) {
+                    WarehouseSpace::Empty => continue,
+                    WarehouseSpace::LeftBox => {
+                        if !to_move.contains(&dep_pos) {
+                            to_check.push(dep_pos);
+                            to_move.insert(dep_pos);
+                        }
+                    }
+                    WarehouseSpace::RightBox => {
+                        if !to_move.contains(&dep_pos) {
+                            to_check.push(dep_pos);
+                            to_move.insert(dep_pos);
+                        }
+                    }
+                    WarehouseSpace::Wall => {
+                        can_move = false;
+                    }
+                }
+            }
+            if !can_move {
+                break;
+            }
+        }
+
+        if can_move {
+            let to_move_including_originals = to_move
+                .iter()
+                .map(|coord| (*coord, *map.get(coord)))
+                .collect::<Vec<_>>();
+            to_move_including_originals.iter().for_each(|(coord, _)| {
+                map.set(coord, WarehouseSpace::Empty);
+            });
+            to_move_including_originals
+                .into_iter()
+                .for_each(|(coord, original)| {
+                    map.set(&(coord + instruction), original);
+                });
+            map.set(&target_position, WarehouseSpace::Empty);
+            *position = *position + instruction;
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Warehouse, ParseError> {
+    let (map, moves_str) = parse::section_pair(input)?;
+    let (narrow, markers) = Grid::from_text(map, '#', "@", |c| "#.O@".contains(c).then_some(c))?;
+    let robot = markers.single('@')?;
+    let robot_position = Vector2i::new(2 * robot.x, robot.y);
+
+    let size = Vector2i::new(2 * narrow.size.x, narrow.size.y);
+    let mut grid = Grid::empty(size, WarehouseSpace::Wall);
+    for coord in narrow.coordinates() {
+        let (left, right) = match narrow.get(&coord) {
+            '#' => (WarehouseSpace::Wall, WarehouseSpace::Wall),
+            'O' => (WarehouseSpace::LeftBox, WarehouseSpace::RightBox),
+            _ => (WarehouseSpace::Empty, WarehouseSpace::Empty),
+        };
+        grid.set(&Vector2i::new(2 * coord.x, coord.y), left);
+        grid.set(&Vector2i::new(2 * coord.x + 1, coord.y), right);
+    }
+
+    let moves = parse_moves(moves_str)?;
+    Ok((grid, robot_position, moves))
+}
@@ -0,0 +1,156 @@
+use aoc_utils::search::{self, SearchProblem};
+use aoc_utils::{Example, Grid, ParseError, Solution, Vector2i};
+use std::collections::HashSet;
+
+pub struct Maze {
+    map: Grid<bool>,
+    start: Vector2i,
+    end: Vector2i,
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub struct MazePosition {
+    location: Vector2i,
+    rotation: Vector2i,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Maze;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(TEST_INPUT).part1("7036").part2("45"),
+        Example::new(TEST_INPUT2).part1("11048").part2("64"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(maze: &Self::Input) -> Option<usize> {
+        let (points, _) = solve_maze(maze)?;
+        Some(points)
+    }
+
+    fn part2(maze: &Self::Input) -> Option<usize> {
+        let (_, optimal_positions) = solve_maze(maze)?;
+        Some(optimal_positions.len())
+    }
+}
+
+impl SearchProblem for Maze {
+    type State = MazePosition;
+
+    fn neighbors(&self, position: &MazePosition) -> impl Iterator<Item = (usize, MazePosition)> {
+        let forward = MazePosition {
+            location: position.location + position.rotation,
+            rotation: position.rotation,
+        };
+        let left = MazePosition {
+            location: position.location,
+            rotation: position.rotation.rotate_left(),
+        };
+        let right = MazePosition {
+            location: position.location,
+            rotation: position.rotation.rotate_right(),
+        };
+        [(1000, left), (1000, right)]
+            .into_iter()
+            .chain((*self.map.get(&forward.location)).then_some((1, forward)))
+    }
+
+    fn is_goal(&self, position: &MazePosition) -> bool {
+        position.location == self.end
+    }
+
+    fn heuristic(&self, position: &MazePosition) -> usize {
+        (self.end - position.location).manhattan_length() as usize
+    }
+}
+
+/// The lowest score through the maze, with every tile on some path that scores it, or `None`
+/// when the end can't be reached.
+fn solve_maze(maze: &Maze) -> Option<(usize, HashSet<Vector2i>)> {
+    let starting_position = MazePosition {
+        location: maze.start,
+        rotation: Vector2i::new(1, 0),
+    };
+    let paths = search::astar(maze, starting_position);
+    let optimal_distance = paths.goal_distance()?;
+
+    let end_positions = Vector2i::DIRECTION_VECTORS
+        .into_iter()
+        .map(|rotation| MazePosition {
+            location: maze.end,
+            rotation,
+        })
+        .filter(|position| paths.distance(position) == Some(optimal_distance));
+    let visited_nodes = paths
+        .optimal_paths(end_positions)
+        .nodes()
+        .iter()
+        .map(|position| position.location)
+        .collect();
+    Some((optimal_distance, visited_nodes))
+}
+
+fn parse_input(input: &str) -> Result<Maze, ParseError> {
+    let (map, markers) = Grid::from_text(input, false, "SE", |c| match c {
+        '#' => Some(false),
+        '.' | 'S' | 'E' => Some(true),
+        _ => None,
+    })?;
+    Ok(Maze {
+        map,
+        start: markers.single('S')?,
+        end: markers.single('E')?,
+    })
+}
+
+const TEST_INPUT: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+const TEST_INPUT2: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+aoc_utils::example_tests!(Day16);
+
+#[test]
+fn test_unreachable_end() {
+    let maze = parse_input("#####\n#S#E#\n#####").unwrap();
+    assert_eq!(solve_maze(&maze), None);
+}
@@ -0,0 +1,216 @@
+use aoc_utils::{ParseError, parse};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    /// The opcode for a 3-bit number, ignoring any higher bits.
+    pub fn from_bits(bits: u8) -> Opcode {
+        Opcode::ALL[(bits & 7) as usize]
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// Whether the operand is a combo operand, which can stand for a register.
+    pub fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+/// One opcode with its operand, as it appears in a program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    /// The operand as it is used: a literal, or for combo operands also a register name.
+    pub fn operand_text(&self) -> String {
+        match (self.opcode.takes_combo(), self.operand) {
+            (true, 4) => "A".to_string(),
+            (true, 5) => "B".to_string(),
+            (true, 6) => "C".to_string(),
+            (_, operand) => operand.to_string(),
+        }
+    }
+
+    /// What the instruction does, written like `B = A % 8`.
+    pub fn pseudo_code(&self) -> String {
+        let operand = self.operand_text();
+        match self.opcode {
+            Opcode::Adv => format!("A = A >> {operand}"),
+            Opcode::Bxl => format!("B = B ^ {operand}"),
+            Opcode::Bst => format!("B = {operand} % 8"),
+            Opcode::Jnz => format!("if A != 0 jump to {operand}"),
+            Opcode::Bxc => "B = B ^ C".to_string(),
+            Opcode::Out => format!("output {operand} % 8"),
+            Opcode::Bdv => format!("B = A >> {operand}"),
+            Opcode::Cdv => format!("C = A >> {operand}"),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // bxc ignores its operand, so only show it when it would be lost otherwise.
+        if self.opcode == Opcode::Bxc && self.operand == 0 {
+            return write!(f, "bxc");
+        }
+        write!(f, "{} {}", self.opcode.mnemonic(), self.operand_text())
+    }
+}
+
+/// The instruction starting at `address`, or `None` if there's no opcode and operand there.
+pub fn decode_at(program: &[u8], address: usize) -> Option<Instruction> {
+    Some(Instruction {
+        opcode: Opcode::from_bits(*program.get(address)?),
+        operand: *program.get(address + 1)?,
+    })
+}
+
+/// The instructions of a program with their addresses. A final opcode without an operand is
+/// left out, as the computer halts before it.
+pub fn decode(program: &[u8]) -> Vec<(usize, Instruction)> {
+    (0..program.len())
+        .step_by(2)
+        .map_while(|address| Some((address, decode_at(program, address)?)))
+        .collect()
+}
+
+/// One line per instruction like `4: bst A`, with the pseudo-code as a comment if asked for.
+///
+/// The output can be read back by `assemble`.
+pub fn disassemble(program: &[u8], pseudo_code: bool) -> String {
+    decode(program)
+        .into_iter()
+        .map(|(address, instruction)| {
+            let line = format!("{address:>2}: {instruction}");
+            if pseudo_code {
+                format!("{line:<12}; {}", instruction.pseudo_code())
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Turns mnemonic text like `bst A` back into a program.
+///
+/// Blank lines, `;` comments and `4:` style addresses in front of instructions are ignored.
+pub fn assemble(text: &str) -> Result<Vec<u8>, ParseError> {
+    let mut program = Vec::new();
+    for line in text.lines() {
+        let code = line.split(';').next().unwrap_or("");
+        let code = match code.split_once(':') {
+            Some((address, rest)) if address.trim().parse::<usize>().is_ok() => rest,
+            _ => code,
+        };
+        let mut words = code.split_whitespace();
+        let Some(mnemonic) = words.next() else {
+            continue;
+        };
+        let opcode = Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+            .ok_or_else(|| ParseError::new(mnemonic, "an instruction like `adv` or `out`"))?;
+        let operand = match (opcode, words.next()) {
+            (Opcode::Bxc, None) => 0,
+            (_, None) => return Err(ParseError::new(&code[code.len()..], "an operand")),
+            (_, Some(word)) => parse_operand(opcode, word)?,
+        };
+        if let Some(extra) = words.next() {
+            return Err(ParseError::new(extra, "the end of the line"));
+        }
+        program.extend([opcode as u8, operand]);
+    }
+    Ok(program)
+}
+
+fn parse_operand(opcode: Opcode, word: &str) -> Result<u8, ParseError> {
+    let register = match word {
+        "A" => Some(4),
+        "B" => Some(5),
+        "C" => Some(6),
+        _ => None,
+    };
+    match register {
+        Some(operand) if opcode.takes_combo() => Ok(operand),
+        _ => {
+            let expected = if opcode.takes_combo() {
+                "a number from 0 to 7 or a register"
+            } else {
+                "a number from 0 to 7"
+            };
+            match parse::number::<u8>(word) {
+                Ok(operand) if operand < 8 => Ok(operand),
+                _ => Err(ParseError::new(word, expected)),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_assembly() {
+    let program = vec![2, 4, 1, 1, 7, 5, 4, 0, 0, 3, 5, 5, 3, 0];
+    assert_eq!(
+        disassemble(&program, false),
+        " 0: bst A\n 2: bxl 1\n 4: cdv B\n 6: bxc\n 8: adv 3\n10: out B\n12: jnz 0"
+    );
+    assert_eq!(
+        disassemble(&program[..4], true),
+        " 0: bst A   ; B = A % 8\n 2: bxl 1   ; B = B ^ 1"
+    );
+    assert_eq!(assemble(&disassemble(&program, true)).unwrap(), program);
+    assert_eq!(
+        assemble("bxc 3\n\nout 7 ; reserved").unwrap(),
+        vec![4, 3, 5, 7]
+    );
+
+    assert_eq!(
+        assemble("bst D").unwrap_err().expected,
+        "a number from 0 to 7 or a register"
+    );
+    assert_eq!(
+        assemble("jnz A").unwrap_err().expected,
+        "a number from 0 to 7"
+    );
+    assert_eq!(assemble("mul 3").unwrap_err().text, "mul");
+    assert!(assemble("out").is_err());
+}
@@ -0,0 +1,169 @@
+use crate::assembly::{Opcode, decode_at};
+use crate::{Computer, STEP_BUDGET, VmError};
+
+/// A program decoded once up front, so it can be run for many values of A without cloning the
+/// computer or matching on raw opcodes every step.
+#[derive(Debug, Clone)]
+pub struct CompiledProgram {
+    /// The operation starting at each address, so jumps to odd addresses work too.
+    operations: Vec<Operation>,
+    b: u64,
+    c: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Adv(Combo),
+    Bxl(u64),
+    Bst(Combo),
+    Jnz(usize),
+    Bxc,
+    Out(Combo),
+    Bdv(Combo),
+    Cdv(Combo),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Combo {
+    Literal(u64),
+    A,
+    B,
+    C,
+    Reserved,
+}
+
+impl Combo {
+    fn new(operand: u8) -> Combo {
+        match operand {
+            0..=3 => Combo::Literal(operand as u64),
+            4 => Combo::A,
+            5 => Combo::B,
+            6 => Combo::C,
+            _ => Combo::Reserved,
+        }
+    }
+}
+
+impl CompiledProgram {
+    /// Compiles the computer's program, keeping its B and C registers as the starting values.
+    pub fn new(computer: &Computer) -> CompiledProgram {
+        let program = computer.program();
+        let operations = (0..)
+            .map_while(|address| decode_at(program, address))
+            .map(|instruction| {
+                let combo = Combo::new(instruction.operand);
+                let literal = instruction.operand as u64;
+                match instruction.opcode {
+                    Opcode::Adv => Operation::Adv(combo),
+                    Opcode::Bxl => Operation::Bxl(literal),
+                    Opcode::Bst => Operation::Bst(combo),
+                    Opcode::Jnz => Operation::Jnz(instruction.operand as usize),
+                    Opcode::Bxc => Operation::Bxc,
+                    Opcode::Out => Operation::Out(combo),
+                    Opcode::Bdv => Operation::Bdv(combo),
+                    Opcode::Cdv => Operation::Cdv(combo),
+                }
+            })
+            .collect();
+        let registers = computer.registers();
+        CompiledProgram {
+            operations,
+            b: registers.b,
+            c: registers.c,
+        }
+    }
+
+    /// Runs the program with register A set to `a`, handing every output digit to `emit`.
+    ///
+    /// Returns `Ok(false)` as soon as `emit` does, and `Ok(true)` once the program halts.
+    pub fn run(&self, a: u64, mut emit: impl FnMut(u8) -> bool) -> Result<bool, VmError> {
+        let (mut a, mut b, mut c) = (a, self.b, self.c);
+        let mut address = 0;
+        for _ in 0..STEP_BUDGET {
+            let Some(operation) = self.operations.get(address) else {
+                return Ok(true);
+            };
+            let combo = |combo: Combo| match combo {
+                Combo::Literal(value) => Ok(value),
+                Combo::A => Ok(a),
+                Combo::B => Ok(b),
+                Combo::C => Ok(c),
+                Combo::Reserved => Err(VmError::ReservedOperand { address }),
+            };
+            let shifted_a = |shift: u64| {
+                u32::try_from(shift)
+                    .ok()
+                    .and_then(|bits| a.checked_shr(bits))
+                    .ok_or(VmError::ShiftOutOfRange { address, shift })
+            };
+            let mut next_address = address + 2;
+            match *operation {
+                Operation::Adv(operand) => a = shifted_a(combo(operand)?)?,
+                Operation::Bxl(operand) => b ^= operand,
+                Operation::Bst(operand) => b = combo(operand)? % 8,
+                Operation::Jnz(target) => {
+                    if a != 0 {
+                        next_address = target;
+                    }
+                }
+                Operation::Bxc => b ^= c,
+                Operation::Out(operand) => {
+                    if !emit((combo(operand)? % 8) as u8) {
+                        return Ok(false);
+                    }
+                }
+                Operation::Bdv(operand) => b = shifted_a(combo(operand)?)?,
+                Operation::Cdv(operand) => c = shifted_a(combo(operand)?)?,
+            }
+            address = next_address;
+        }
+        Err(VmError::StepBudgetExceeded {
+            budget: STEP_BUDGET,
+        })
+    }
+
+    /// Whether running with `a` outputs exactly `target`, stopping at the first wrong digit.
+    pub fn outputs(&self, a: u64, target: &[u8]) -> Result<bool, VmError> {
+        let mut matched = 0;
+        let halted = self.run(a, |digit| {
+            let expected = target.get(matched) == Some(&digit);
+            matched += 1;
+            expected
+        })?;
+        Ok(halted && matched == target.len())
+    }
+
+    /// Everything the program outputs when run with `a`.
+    pub fn output(&self, a: u64) -> Result<Vec<u8>, VmError> {
+        let mut output = Vec::new();
+        self.run(a, |digit| {
+            output.push(digit);
+            true
+        })?;
+        Ok(output)
+    }
+}
+
+#[test]
+fn test_compiled_program() {
+    let input =
+        "Register A: 0\nRegister B: 3\nRegister C: 0\n\nProgram: 2,4,1,1,7,5,4,0,0,3,5,5,3,0";
+    let computer = crate::parse_input(input).unwrap();
+    let compiled = CompiledProgram::new(&computer);
+    for a in [0, 1, 7, 8, 12345, 117440, u64::MAX] {
+        let mut interpreted = computer.clone();
+        interpreted.set_register(crate::Register::A, a);
+        let expected = interpreted.run(STEP_BUDGET).map(<[u8]>::to_vec);
+        assert_eq!(compiled.output(a), expected);
+        let output = expected.unwrap();
+        assert_eq!(compiled.outputs(a, &output), Ok(true));
+        assert_eq!(compiled.outputs(a, &output[1..]), Ok(false));
+    }
+
+    let reserved =
+        crate::parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7").unwrap();
+    assert_eq!(
+        CompiledProgram::new(&reserved).output(1),
+        Err(VmError::ReservedOperand { address: 0 })
+    );
+}
@@ -0,0 +1,283 @@
+use crate::{Computer, Register, STEP_BUDGET, Step, VmError};
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "commands: step [count], continue, break <ip>, watch <register>, \
+set <register> <value>, registers, quit";
+
+/// Runs a program under control of typed commands, stopping at breakpoints and whenever a
+/// watched register changes.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    watches: Vec<Register>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    Continue,
+    Break(usize),
+    Watch(Register),
+    Set(Register, u64),
+    Registers,
+    Quit,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Debugger {
+        Debugger {
+            computer,
+            breakpoints: BTreeSet::new(),
+            watches: Vec::new(),
+        }
+    }
+
+    /// Reads commands from `input` until it ends or says `quit`, writing the results to `output`.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        writeln!(output, "{}", self.location())?;
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match parse_command(&line) {
+                Ok(Command::Quit) => break,
+                Ok(command) => writeln!(output, "{}", self.execute(command))?,
+                Err(message) => writeln!(output, "{message}\n{HELP}")?,
+            }
+        }
+        Ok(())
+    }
+
+    fn execute(&mut self, command: Command) -> String {
+        match command {
+            Command::Step(count) => {
+                let mut lines = Vec::new();
+                for _ in 0..count {
+                    match self.computer.step() {
+                        Ok(Some(step)) => {
+                            lines.push(format_step(&step));
+                            lines.extend(self.watch_changes(&step));
+                        }
+                        Ok(None) => break,
+                        Err(error) => {
+                            lines.push(format!("error: {error}"));
+                            break;
+                        }
+                    }
+                }
+                lines.push(self.location());
+                lines.join("\n")
+            }
+            Command::Continue => {
+                let mut lines = Vec::new();
+                for steps in 1.. {
+                    let step = match self.computer.step() {
+                        Ok(Some(step)) => step,
+                        Ok(None) => break,
+                        Err(error) => {
+                            lines.push(format!("error: {error}"));
+                            break;
+                        }
+                    };
+                    let changes = self.watch_changes(&step);
+                    if !changes.is_empty() {
+                        lines.push(format_step(&step));
+                        lines.extend(changes);
+                        break;
+                    }
+                    if self
+                        .breakpoints
+                        .contains(&self.computer.instruction_pointer())
+                    {
+                        lines.push(format!(
+                            "breakpoint at {}",
+                            self.computer.instruction_pointer()
+                        ));
+                        break;
+                    }
+                    if steps == STEP_BUDGET {
+                        lines.push(format!("paused after {steps} steps"));
+                        break;
+                    }
+                }
+                lines.push(self.location());
+                lines.join("\n")
+            }
+            Command::Break(address) => {
+                self.breakpoints.insert(address);
+                format!("breakpoint set at {address}")
+            }
+            Command::Watch(register) => {
+                if !self.watches.contains(&register) {
+                    self.watches.push(register);
+                }
+                format!("watching {register:?}")
+            }
+            Command::Set(register, value) => {
+                self.computer.set_register(register, value);
+                self.location()
+            }
+            Command::Registers | Command::Quit => self.location(),
+        }
+    }
+
+    fn watch_changes(&self, step: &Step) -> Vec<String> {
+        self.watches
+            .iter()
+            .filter(|register| step.before.get(**register) != step.after.get(**register))
+            .map(|register| {
+                format!(
+                    "{register:?} changed from {} to {}",
+                    step.before.get(*register),
+                    step.after.get(*register)
+                )
+            })
+            .collect()
+    }
+
+    /// Where the program is, its registers and everything output so far.
+    fn location(&self) -> String {
+        let registers = self.computer.registers();
+        let next = match self.computer.next_instruction() {
+            Some(instruction) => format!("next {instruction}"),
+            None => "halted".to_string(),
+        };
+        let output: Vec<String> = self.computer.output().iter().map(u8::to_string).collect();
+        let output = if output.is_empty() {
+            "no output".to_string()
+        } else {
+            format!("output {}", output.join(","))
+        };
+        format!(
+            "ip {}, {next} | A={} B={} C={} | {output}",
+            self.computer.instruction_pointer(),
+            registers.a,
+            registers.b,
+            registers.c,
+        )
+    }
+}
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let number = |word: &str| {
+        word.parse()
+            .map_err(|_| format!("expected a number, found `{word}`"))
+    };
+    let register = |word: &str| match word {
+        "A" | "a" => Ok(Register::A),
+        "B" | "b" => Ok(Register::B),
+        "C" | "c" => Ok(Register::C),
+        _ => Err(format!("expected A, B or C, found `{word}`")),
+    };
+    match words.as_slice() {
+        ["step" | "s"] => Ok(Command::Step(1)),
+        ["step" | "s", count] => Ok(Command::Step(number(count)?)),
+        ["continue" | "c"] => Ok(Command::Continue),
+        ["break" | "b", address] => Ok(Command::Break(number(address)?)),
+        ["watch" | "w", name] => Ok(Command::Watch(register(name)?)),
+        ["set", name, value] => {
+            let value = value
+                .parse()
+                .map_err(|_| format!("expected a number, found `{value}`"))?;
+            Ok(Command::Set(register(name)?, value))
+        }
+        ["registers" | "r"] => Ok(Command::Registers),
+        ["quit" | "q"] => Ok(Command::Quit),
+        _ => Err(format!("unknown command `{}`", line.trim())),
+    }
+}
+
+fn format_step(step: &Step) -> String {
+    let registers = step.after;
+    let output = step
+        .output
+        .map_or(String::new(), |value| format!(" -> output {value}"));
+    format!(
+        "{:>2}: {:<8} A={} B={} C={}{output}",
+        step.address,
+        step.instruction.to_string(),
+        registers.a,
+        registers.b,
+        registers.c
+    )
+}
+
+/// Runs the program to the end, writing every executed instruction as a CSV row.
+///
+/// Stops with an error if the computer does, or if the program is still running after
+/// `STEP_BUDGET` steps.
+pub fn write_trace(mut computer: Computer, mut output: impl Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "step,address,instruction,a_before,b_before,c_before,a_after,b_after,c_after,output"
+    )?;
+    for count in 0..STEP_BUDGET {
+        let Some(step) = computer.step().map_err(io::Error::other)? else {
+            return Ok(());
+        };
+        let (before, after) = (step.before, step.after);
+        writeln!(
+            output,
+            "{count},{},{},{},{},{},{},{},{},{}",
+            step.address,
+            step.instruction,
+            before.a,
+            before.b,
+            before.c,
+            after.a,
+            after.b,
+            after.c,
+            step.output.map_or(String::new(), |value| value.to_string())
+        )?;
+    }
+    match computer.next_instruction() {
+        None => Ok(()),
+        Some(_) => Err(io::Error::other(VmError::StepBudgetExceeded {
+            budget: STEP_BUDGET,
+        })),
+    }
+}
+
+#[test]
+fn test_debugger() {
+    let computer = crate::parse_input(crate::TEST_INPUT).unwrap();
+
+    let mut trace = Vec::new();
+    write_trace(computer.clone(), &mut trace).unwrap();
+    let trace = String::from_utf8(trace).unwrap();
+    assert_eq!(trace.lines().count(), 1 + 6 * 3);
+    assert_eq!(
+        trace.lines().nth(2).unwrap(),
+        "1,2,out A,14680,0,0,14680,0,0,0"
+    );
+
+    let commands = "step\nbreak 4\ncontinue\nwatch A\nset A 8\ncontinue\nbogus\nquit\nstep";
+    let mut output = Vec::new();
+    Debugger::new(computer)
+        .run(commands.as_bytes(), &mut output)
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "ip 0, next adv 3 | A=117440 B=0 C=0 | no output",
+            " 0: adv 3    A=14680 B=0 C=0",
+            "ip 2, next out A | A=14680 B=0 C=0 | no output",
+            "breakpoint set at 4",
+            "breakpoint at 4",
+            "ip 4, next jnz 0 | A=14680 B=0 C=0 | output 0",
+            "watching A",
+            "ip 4, next jnz 0 | A=8 B=0 C=0 | output 0",
+            " 0: adv 3    A=1 B=0 C=0",
+            "A changed from 8 to 1",
+            "ip 2, next out A | A=1 B=0 C=0 | output 0",
+            "unknown command `bogus`",
+            HELP,
+        ]
+    );
+}
@@ -0,0 +1,318 @@
+use aoc_utils::{Example, ParseError, Solution, parse::KeyValues};
+use assembly::{Instruction, Opcode};
+use compiled::CompiledProgram;
+use std::fmt::Display;
+
+pub mod assembly;
+pub mod compiled;
+pub mod debugger;
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    program: Vec<u8>,
+    output: Vec<u8>,
+    instruction_pointer: usize,
+    registers: Registers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Registers {
+    pub fn get(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+            Register::C => self.c,
+        }
+    }
+
+    pub fn set(&mut self, register: Register, value: u64) {
+        match register {
+            Register::A => self.a = value,
+            Register::B => self.b = value,
+            Register::C => self.c = value,
+        }
+    }
+}
+
+/// How many instructions a program may run before it is assumed to loop forever.
+pub const STEP_BUDGET: usize = 1_000_000;
+
+/// Why the computer had to stop before the program halted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VmError {
+    /// Combo operand 7, which the puzzle says never appears in valid programs.
+    ReservedOperand { address: usize },
+    /// A shift by at least the width of a register.
+    ShiftOutOfRange { address: usize, shift: u64 },
+    /// The program ran for more steps than allowed, probably because it never halts.
+    StepBudgetExceeded { budget: usize },
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VmError::ReservedOperand { address } => {
+                write!(f, "reserved combo operand 7 at address {address}")
+            }
+            VmError::ShiftOutOfRange { address, shift } => {
+                write!(f, "shift by {shift} bits at address {address}")
+            }
+            VmError::StepBudgetExceeded { budget } => {
+                write!(f, "still running after {budget} steps")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+/// One executed instruction, with the registers around it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub address: usize,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u8>,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Computer;
+    type Answer1 = Result<String, VmError>;
+    type Answer2 = Option<u64>;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT)
+        .part1("0,3,5,4,3,0")
+        .part2("117440")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(computer: &Self::Input) -> Result<String, VmError> {
+        let output = execute_program(computer.clone())?;
+        Ok(output
+            .into_iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+
+    fn part2(computer: &Self::Input) -> Option<u64> {
+        find_correct_a(computer)
+    }
+}
+
+impl Computer {
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    pub fn set_register(&mut self, register: Register, value: u64) {
+        self.registers.set(register, value);
+    }
+
+    /// The instruction that runs next, or `None` once the program has halted.
+    pub fn next_instruction(&self) -> Option<Instruction> {
+        assembly::decode_at(&self.program, self.instruction_pointer)
+    }
+
+    /// Runs a single instruction, or returns `None` if the program has already halted.
+    pub fn step(&mut self) -> Result<Option<Step>, VmError> {
+        let Some(instruction) = self.next_instruction() else {
+            return Ok(None);
+        };
+        let address = self.instruction_pointer;
+        let before = self.registers;
+        let mut output = None;
+        let mut next_pointer = self.instruction_pointer + 2;
+        let operand = instruction.operand;
+
+        match instruction.opcode {
+            Opcode::Adv => self.registers.a = self.shifted_a(operand)?,
+            Opcode::Bxl => self.registers.b ^= operand as u64,
+            Opcode::Bst => self.registers.b = self.combo_operand(operand)? % 8,
+            Opcode::Jnz => {
+                if self.registers.a != 0 {
+                    next_pointer = operand as usize;
+                }
+            }
+            Opcode::Bxc => self.registers.b ^= self.registers.c,
+            Opcode::Out => output = Some((self.combo_operand(operand)? % 8) as u8),
+            Opcode::Bdv => self.registers.b = self.shifted_a(operand)?,
+            Opcode::Cdv => self.registers.c = self.shifted_a(operand)?,
+        }
+        self.output.extend(output);
+        self.instruction_pointer = next_pointer;
+
+        Ok(Some(Step {
+            address,
+            instruction,
+            before,
+            after: self.registers,
+            output,
+        }))
+    }
+
+    /// Runs until the program halts, giving up after `budget` steps.
+    pub fn run(&mut self, budget: usize) -> Result<&[u8], VmError> {
+        for _ in 0..budget {
+            if self.step()?.is_none() {
+                return Ok(&self.output);
+            }
+        }
+        match self.next_instruction() {
+            None => Ok(&self.output),
+            Some(_) => Err(VmError::StepBudgetExceeded { budget }),
+        }
+    }
+
+    fn combo_operand(&self, operand: u8) -> Result<u64, VmError> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.registers.a),
+            5 => Ok(self.registers.b),
+            6 => Ok(self.registers.c),
+            _ => Err(VmError::ReservedOperand {
+                address: self.instruction_pointer,
+            }),
+        }
+    }
+
+    /// A divided by two to the power of the combo operand, which is a right shift.
+    fn shifted_a(&self, operand: u8) -> Result<u64, VmError> {
+        let shift = self.combo_operand(operand)?;
+        u32::try_from(shift)
+            .ok()
+            .and_then(|shift| self.registers.a.checked_shr(shift))
+            .ok_or(VmError::ShiftOutOfRange {
+                address: self.instruction_pointer,
+                shift,
+            })
+    }
+}
+
+fn execute_program(mut computer: Computer) -> Result<Vec<u8>, VmError> {
+    computer.run(STEP_BUDGET)?;
+    Ok(computer.output)
+}
+
+/// Finds the smallest value for register A that makes the program output itself.
+///
+/// Assumes the program is a loop that outputs one digit per pass, then shifts A right by three
+/// bits until it is zero, like every puzzle input does. Then the last output digit only depends
+/// on the highest three bits of A, so A can be built up three bits at a time, from the last
+/// digit backwards, backtracking whenever no choice of bits produces the next digit.
+fn find_correct_a(computer: &Computer) -> Option<u64> {
+    let compiled = CompiledProgram::new(computer);
+    extend_a(&compiled, &computer.program, 0, computer.program.len())
+}
+
+/// Tries every next three bits of A below `prefix` so the output matches the program from
+/// `remaining` onwards, smallest first.
+fn extend_a(
+    compiled: &CompiledProgram,
+    program: &[u8],
+    prefix: u64,
+    remaining: usize,
+) -> Option<u64> {
+    if remaining == 0 {
+        return Some(prefix);
+    }
+    (0..8).find_map(|bits| {
+        let a = prefix.checked_mul(8)? + bits;
+        if compiled
+            .outputs(a, &program[remaining - 1..])
+            .unwrap_or(false)
+        {
+            extend_a(compiled, program, a, remaining - 1)
+        } else {
+            None
+        }
+    })
+}
+
+fn parse_input(input: &str) -> Result<Computer, ParseError> {
+    let fields = KeyValues::parse(input)?;
+    Ok(Computer {
+        program: fields.comma_list("Program")?,
+        instruction_pointer: 0,
+        output: Vec::new(),
+        registers: Registers {
+            a: fields.number("Register A")?,
+            b: fields.number("Register B")?,
+            c: fields.number("Register C")?,
+        },
+    })
+}
+
+const TEST_INPUT: &str = "Register A: 117440
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+aoc_utils::example_tests!(Day17);
+
+#[test]
+fn test_no_quine() {
+    let computer =
+        parse_input("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4").unwrap();
+    assert_eq!(find_correct_a(&computer), None);
+}
+
+#[test]
+fn test_vm_errors() {
+    let computer = |a: u64, program: &str| {
+        let input = format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}");
+        parse_input(&input).unwrap()
+    };
+    assert_eq!(
+        execute_program(computer(1, "0,1,5,7")),
+        Err(VmError::ReservedOperand { address: 2 })
+    );
+    assert_eq!(
+        execute_program(computer(64, "0,4")),
+        Err(VmError::ShiftOutOfRange {
+            address: 0,
+            shift: 64
+        })
+    );
+    assert_eq!(
+        execute_program(computer(1, "3,0")),
+        Err(VmError::StepBudgetExceeded {
+            budget: STEP_BUDGET
+        })
+    );
+    assert_eq!(execute_program(computer(u64::MAX, "0,3,5,4")), Ok(vec![7]));
+}
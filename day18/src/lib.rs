@@ -0,0 +1,234 @@
+use aoc_utils::{Example, Grid, ParseError, Solution, Vector2i, parse};
+
+const EXAMPLE_PARAMS: MemoryParams = MemoryParams {
+    grid_size: 7,
+    fallen_bytes: 12,
+};
+
+/// How big the memory space is and how many bytes fall for part 1, both smaller in the example.
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryParams {
+    pub grid_size: i64,
+    pub fallen_bytes: usize,
+}
+
+impl Default for MemoryParams {
+    fn default() -> MemoryParams {
+        MemoryParams {
+            grid_size: 71,
+            fallen_bytes: 1024,
+        }
+    }
+}
+
+/// The falling bytes, and how much of them to look at.
+#[derive(Debug, Clone)]
+pub struct MemorySpace {
+    pub byte_positions: Vec<Vector2i>,
+    /// Width and height of the memory space.
+    pub grid_size: i64,
+    /// How many bytes have fallen for part 1.
+    pub fallen_bytes: usize,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = MemorySpace;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<String>;
+    type Params = MemoryParams;
+
+    const EXAMPLES: &'static [Example<MemoryParams>] =
+        &[Example::with_params(TEST_INPUT, EXAMPLE_PARAMS)
+            .part1("22")
+            .part2("6,1")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, MemoryParams::default())
+    }
+
+    fn parse_with(input: &str, params: &MemoryParams) -> Result<Self::Input, ParseError> {
+        parse_input(input, *params)
+    }
+
+    fn part1(memory: &Self::Input) -> Option<usize> {
+        let (length, _) = find_shortest_path(&memory.fallen_grid())?;
+        Some(length)
+    }
+
+    fn part2(memory: &Self::Input) -> Option<String> {
+        let byte = find_first_blocking_byte(&memory.byte_positions, memory.grid_size)?;
+        Some(format!("{},{}", byte.position.x, byte.position.y))
+    }
+}
+
+impl MemorySpace {
+    /// The memory space once `fallen_bytes` bytes have fallen.
+    pub fn fallen_grid(&self) -> Grid<bool> {
+        grid_after_n_bytes(&self.byte_positions, self.grid_size, self.fallen_bytes)
+    }
+
+    /// Draws the corrupted cells as `#` and the shortest path out as `O`.
+    pub fn render(&self) -> String {
+        let grid = self.fallen_grid();
+        let path = find_shortest_path(&grid).map_or(Vec::new(), |(_, path)| path);
+        grid.render(|open| if *open { '.' } else { '#' })
+            .mark(&path, 'O')
+            .to_string()
+    }
+}
+
+/// The length of the shortest way out, with every coordinate along it from start to exit.
+pub fn find_shortest_path(grid: &Grid<bool>) -> Option<(usize, Vec<Vector2i>)> {
+    let exit = grid.size - Vector2i::new(1, 1);
+    let paths = grid
+        .graph(|open| *open)
+        .build()
+        .shortest_paths(Vector2i::new(0, 0), Some(exit));
+    Some((paths.distance(&exit)?, paths.path_to(&exit)?))
+}
+
+/// The first byte that cuts the exit off from the start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockingByte {
+    /// Zero-based position of the byte in the input.
+    pub index: usize,
+    pub position: Vector2i,
+}
+
+/// Binary searches for the first byte count that leaves no way out, as blocking only gets worse
+/// as more bytes fall.
+pub fn find_first_blocking_byte(
+    byte_positions: &[Vector2i],
+    grid_size: i64,
+) -> Option<BlockingByte> {
+    let is_reachable = |n: usize| {
+        let grid = grid_after_n_bytes(byte_positions, grid_size, n);
+        let exit = grid.size - Vector2i::new(1, 1);
+        grid.distances([Vector2i::new(0, 0)], |open| *open)
+            .get(&exit)
+            .is_some()
+    };
+    if is_reachable(byte_positions.len()) {
+        return None;
+    }
+    // The exit is reachable after `reachable` bytes but not after `blocked` bytes.
+    let (mut reachable, mut blocked) = (0, byte_positions.len());
+    while blocked - reachable > 1 {
+        let middle = (reachable + blocked) / 2;
+        if is_reachable(middle) {
+            reachable = middle;
+        } else {
+            blocked = middle;
+        }
+    }
+    Some(BlockingByte {
+        index: blocked - 1,
+        position: byte_positions[blocked - 1],
+    })
+}
+
+fn grid_after_n_bytes(byte_positions: &[Vector2i], grid_size: i64, n: usize) -> Grid<bool> {
+    let mut grid = Grid::empty(Vector2i::new(grid_size, grid_size), false);
+    grid.coordinates().into_iter().for_each(|coord| {
+        grid.set(&coord, true);
+    });
+    byte_positions.iter().take(n).for_each(|coord| {
+        grid.set(coord, false);
+    });
+
+    grid
+}
+
+fn parse_input(input: &str, params: MemoryParams) -> Result<MemorySpace, ParseError> {
+    let MemoryParams {
+        grid_size,
+        fallen_bytes,
+    } = params;
+    let byte_positions = input
+        .lines()
+        .map(|line| {
+            let [x, y] = parse::fixed_integers(line)?;
+            if !(0..grid_size).contains(&x) || !(0..grid_size).contains(&y) {
+                return Err(ParseError::new(line, "coordinates inside the memory space"));
+            }
+            Ok(Vector2i::new(x, y))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(MemorySpace {
+        byte_positions,
+        grid_size,
+        fallen_bytes,
+    })
+}
+
+const TEST_INPUT: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+aoc_utils::example_tests!(Day18);
+
+#[test]
+fn test_first_blocking_byte() {
+    let byte_positions = parse_input(TEST_INPUT, EXAMPLE_PARAMS)
+        .unwrap()
+        .byte_positions;
+    assert_eq!(
+        find_first_blocking_byte(&byte_positions, 7),
+        Some(BlockingByte {
+            index: 20,
+            position: Vector2i::new(6, 1)
+        })
+    );
+    assert_eq!(find_first_blocking_byte(&byte_positions[..20], 7), None);
+
+    let truncated = parse_input("5,4\n4,2\n4,5", EXAMPLE_PARAMS).unwrap();
+    assert_eq!(Day18::part2(&truncated), None);
+}
+
+#[test]
+fn test_parse_input() {
+    let memory = parse_input("0,0\n10,3\n2,1", MemoryParams::default()).unwrap();
+    assert_eq!(memory.grid_size, 71);
+    assert_eq!(memory.fallen_bytes, 1024);
+    assert!(parse_input("1,2\n70,3", MemoryParams::default()).is_ok());
+    assert!(parse_input("1,2\n-1,3", EXAMPLE_PARAMS).is_err());
+    assert!(parse_input("1,2\n7,3", EXAMPLE_PARAMS).is_err());
+
+    let blocked = parse_input("6,5\n5,6", EXAMPLE_PARAMS).unwrap();
+    assert_eq!(Day18::part1(&blocked), None);
+}
+
+#[test]
+fn test_render() {
+    let memory = parse_input(TEST_INPUT, EXAMPLE_PARAMS).unwrap();
+    assert_eq!(
+        memory.render(),
+        "OO.#OOO\n.O#OO#O\n.OOO#OO\n...#OO#\n..#OO#.\n.#.O#..\n#.#OOOO"
+    );
+}
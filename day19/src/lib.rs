@@ -0,0 +1,109 @@
+use aoc_utils::{Example, ParseError, Solution, parse};
+use std::collections::HashMap;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = ();
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT).part1("6").part2("16")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (parts, sequences) = parse_input(input)?;
+        Ok((
+            parts.into_iter().map(str::to_string).collect(),
+            sequences.into_iter().map(str::to_string).collect(),
+        ))
+    }
+
+    fn part1((parts, sequences): &Self::Input) -> usize {
+        number_of_possible_sequence(as_strs(parts), as_strs(sequences))
+    }
+
+    fn part2((parts, sequences): &Self::Input) -> usize {
+        number_of_possibilities(as_strs(parts), as_strs(sequences))
+    }
+}
+
+fn as_strs(strings: &[String]) -> Vec<&str> {
+    strings.iter().map(String::as_str).collect()
+}
+
+fn number_of_possible_sequence(parts: Vec<&str>, sequences: Vec<&str>) -> usize {
+    sequences
+        .into_iter()
+        .filter(|sequence| is_sequence_possible(&parts, sequence))
+        .count()
+}
+
+fn is_sequence_possible(parts: &Vec<&str>, sequence: &str) -> bool {
+    parts.iter().any(|part| {
+        if !sequence.starts_with(*part) {
+            return false;
+        }
+        if sequence == *part {
+            return true;
+        }
+        let trimmed_sequence = &sequence[part.len()..];
+        is_sequence_possible(parts, trimmed_sequence)
+    })
+}
+
+fn number_of_possibilities(parts: Vec<&str>, sequences: Vec<&str>) -> usize {
+    let mut cache = HashMap::new();
+    sequences
+        .into_iter()
+        .map(|sequence| sequence_possibilities(&parts, sequence, &mut cache))
+        .sum()
+}
+
+fn sequence_possibilities<'a>(
+    parts: &[&str],
+    sequence: &'a str,
+    cache: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if let Some(possibilities) = cache.get(sequence) {
+        return *possibilities;
+    }
+    let possibilities = parts
+        .iter()
+        .map(|part| {
+            if !sequence.starts_with(part) {
+                return 0;
+            }
+            if sequence == *part {
+                return 1;
+            }
+            let trimmed_sequence = &sequence[part.len()..];
+            sequence_possibilities(parts, trimmed_sequence, cache)
+        })
+        .sum();
+    cache.insert(sequence, possibilities);
+    possibilities
+}
+
+fn parse_input(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let (parts_str, sequences_str) = parse::section_pair(input)?;
+
+    let parts = parts_str.split(", ").collect();
+    let sequences = sequences_str.lines().collect();
+    Ok((parts, sequences))
+}
+
+const TEST_INPUT: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+aoc_utils::example_tests!(Day19);
@@ -0,0 +1,156 @@
+use aoc_utils::{Example, Grid, ParseError, Solution, Vector2i};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+
+/// How many picoseconds a cheat must save to count for the answers. The example track is too
+/// short for cheats to save the real puzzle's 100, so it counts smaller savings.
+#[derive(Debug, Clone, Copy)]
+pub struct MinSaving(pub u32);
+
+impl Default for MinSaving {
+    fn default() -> MinSaving {
+        MinSaving(100)
+    }
+}
+
+pub struct Racetrack {
+    grid: Grid<bool>,
+    start: Vector2i,
+    end: Vector2i,
+    /// See `MinSaving`.
+    pub min_saving: u32,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Racetrack;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+    type Params = MinSaving;
+
+    const EXAMPLES: &'static [Example<MinSaving>] =
+        &[Example::with_params(TEST_INPUT, MinSaving(50))
+            .part1("1")
+            .part2("285")];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, MinSaving::default())
+    }
+
+    fn parse_with(input: &str, min_saving: &MinSaving) -> Result<Self::Input, ParseError> {
+        parse_input(input, *min_saving)
+    }
+
+    fn part1(racetrack: &Self::Input) -> Option<usize> {
+        count_good_cheats(racetrack, 2, racetrack.min_saving)
+    }
+
+    fn part2(racetrack: &Self::Input) -> Option<usize> {
+        count_good_cheats(racetrack, 20, racetrack.min_saving)
+    }
+}
+
+/// The number of cheats of at most `max_cheat` picoseconds that save at least `min_saving`.
+pub fn count_good_cheats(racetrack: &Racetrack, max_cheat: i64, min_saving: u32) -> Option<usize> {
+    let savings = cheat_savings(racetrack, max_cheat)?;
+    Some(savings.range(min_saving..).map(|(_, count)| count).sum())
+}
+
+/// How many cheats of at most `max_cheat` picoseconds there are for every time they save, or
+/// `None` when the end can't be reached at all.
+pub fn cheat_savings(racetrack: &Racetrack, max_cheat: i64) -> Option<BTreeMap<u32, usize>> {
+    let grid = &racetrack.grid;
+    let from_start = grid.distances([racetrack.start], |open| *open);
+    let to_end = grid.distances([racetrack.end], |open| *open);
+    let base_speed = (*from_start.get(&racetrack.end))?;
+
+    let cheat_offsets: Vec<Vector2i> = (-max_cheat..=max_cheat)
+        .flat_map(|x| (-max_cheat..=max_cheat).map(move |y| Vector2i::new(x, y)))
+        .filter(|offset| (1..=max_cheat).contains(&offset.manhattan_length()))
+        .collect();
+    let (cheat_offsets, to_end) = (&cheat_offsets, &to_end);
+
+    let counts = grid
+        .coordinates()
+        .into_par_iter()
+        .filter_map(|cheat_start| from_start.get(&cheat_start).map(|time| (cheat_start, time)))
+        .fold(
+            || vec![0; base_speed as usize + 1],
+            |mut counts, (cheat_start, time)| {
+                for offset in cheat_offsets {
+                    let Some(remaining) = *to_end.get(&(cheat_start + *offset)) else {
+                        continue;
+                    };
+                    let cheated_speed = time + offset.manhattan_length() as u32 + remaining;
+                    if let Some(saving) = base_speed.checked_sub(cheated_speed) {
+                        counts[saving as usize] += 1;
+                    }
+                }
+                counts
+            },
+        )
+        .reduce_with(|mut total, counts| {
+            total.iter_mut().zip(counts).for_each(|(a, b)| *a += b);
+            total
+        })
+        .unwrap_or_default();
+
+    let savings = counts
+        .into_iter()
+        .enumerate()
+        .filter(|(saving, count)| *saving > 0 && *count > 0)
+        .map(|(saving, count)| (saving as u32, count))
+        .collect();
+    Some(savings)
+}
+
+fn parse_input(input: &str, MinSaving(min_saving): MinSaving) -> Result<Racetrack, ParseError> {
+    let (grid, markers) = Grid::from_text(input, false, "SE", |c| match c {
+        '#' => Some(false),
+        '.' | 'S' | 'E' => Some(true),
+        _ => None,
+    })?;
+    Ok(Racetrack {
+        start: markers.single('S')?,
+        end: markers.single('E')?,
+        grid,
+        min_saving,
+    })
+}
+
+const TEST_INPUT: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+aoc_utils::example_tests!(Day20);
+
+#[test]
+fn test_cheat_savings() {
+    let racetrack = parse_input(TEST_INPUT, MinSaving(50)).unwrap();
+
+    let savings = cheat_savings(&racetrack, 2).unwrap();
+    assert_eq!(savings[&2], 14);
+    assert_eq!(savings[&64], 1);
+    assert_eq!(savings.values().sum::<usize>(), 44);
+
+    assert_eq!(cheat_savings(&racetrack, 20).unwrap()[&76], 3);
+    assert_eq!(count_good_cheats(&racetrack, 20, 50), Some(285));
+
+    let unreachable = parse_input("#####\n#S#E#\n#####", MinSaving::default()).unwrap();
+    assert_eq!(cheat_savings(&unreachable, 2), None);
+}
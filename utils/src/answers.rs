@@ -0,0 +1,149 @@
+use crate::Part;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::PathBuf;
+
+pub const DEFAULT_ANSWERS_DIRECTORY: &str = "answers";
+
+/// The confirmed answers for one day's puzzle input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// How a computed answer compares to the confirmed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✓"),
+            Verdict::Wrong { expected } => write!(f, "✗ (expected {expected})"),
+            Verdict::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part1.is_none() && self.part2.is_none()
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswerError {
+    Io(PathBuf, std::io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Serialize(PathBuf, toml::ser::Error),
+}
+
+impl Display for AnswerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerError::Io(path, error) => {
+                write!(f, "could not access {}: {error}", path.display())
+            }
+            AnswerError::Parse(path, error) => {
+                write!(f, "invalid answers in {}: {error}", path.display())
+            }
+            AnswerError::Serialize(path, error) => {
+                write!(f, "could not write answers to {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswerError {}
+
+/// Keeps confirmed answers as `<answers directory>/<year>/dayNN.toml`.
+#[derive(Debug, Clone)]
+pub struct AnswerStore {
+    pub answers_directory: PathBuf,
+}
+
+impl Default for AnswerStore {
+    fn default() -> Self {
+        AnswerStore::new(DEFAULT_ANSWERS_DIRECTORY)
+    }
+}
+
+impl AnswerStore {
+    pub fn new(answers_directory: impl Into<PathBuf>) -> AnswerStore {
+        AnswerStore {
+            answers_directory: answers_directory.into(),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.answers_directory
+            .join(year.to_string())
+            .join(format!("day{day:02}.toml"))
+    }
+
+    /// Loads the answers for a day, which are empty when nothing was confirmed yet.
+    pub fn load(&self, year: u16, day: u8) -> Result<Answers, AnswerError> {
+        let path = self.path(year, day);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).map_err(|error| AnswerError::Parse(path, error)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(AnswerError::Io(path, error)),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8, answers: &Answers) -> Result<(), AnswerError> {
+        let path = self.path(year, day);
+        let text = toml::to_string(answers)
+            .map_err(|error| AnswerError::Serialize(path.clone(), error))?;
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)
+                .map_err(|error| AnswerError::Io(path.clone(), error))?;
+        }
+        std::fs::write(&path, text).map_err(|error| AnswerError::Io(path, error))
+    }
+}
+
+#[test]
+fn test_answers() {
+    let answers: Answers = toml::from_str("part1 = \"7036\"").unwrap();
+    assert_eq!(answers.check(Part::One, "7036"), Verdict::Correct);
+    assert_eq!(
+        answers.check(Part::One, "7035"),
+        Verdict::Wrong {
+            expected: "7036".to_string()
+        }
+    );
+    assert_eq!(answers.check(Part::Two, "45"), Verdict::Unknown);
+    assert_eq!(toml::to_string(&answers).unwrap(), "part1 = \"7036\"\n");
+}
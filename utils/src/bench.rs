@@ -0,0 +1,181 @@
+use crate::{ParseError, Part, Runnable};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const DEFAULT_BASELINE_PATH: &str = "benchmarks/baseline.json";
+
+/// Median time per phase of one day over several iterations, in milliseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub iterations: u32,
+    pub parse: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<f64>,
+}
+
+impl Measurement {
+    pub fn phases(&self) -> [(&'static str, Option<f64>); 3] {
+        [
+            ("parse", Some(self.parse)),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+pub fn measure(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    iterations: u32,
+) -> Result<Measurement, ParseError> {
+    let runs = (0..iterations.max(1))
+        .map(|_| solution.time(input, parts))
+        .collect::<Result<Vec<_>, _>>()?;
+    let part_median = |part: Part| {
+        let times: Vec<Duration> = runs
+            .iter()
+            .filter_map(|run| run.parts.iter().find(|(p, _)| *p == part))
+            .map(|(_, time)| *time)
+            .collect();
+        (!times.is_empty()).then(|| median_milliseconds(times))
+    };
+    Ok(Measurement {
+        day: solution.day(),
+        iterations: runs.len() as u32,
+        parse: median_milliseconds(runs.iter().map(|run| run.parse).collect()),
+        part1: part_median(Part::One),
+        part2: part_median(Part::Two),
+    })
+}
+
+fn median_milliseconds(mut times: Vec<Duration>) -> f64 {
+    times.sort();
+    times[times.len() / 2].as_secs_f64() * 1000.0
+}
+
+/// A phase that got slower than its baseline by more than the allowed percentage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: f64,
+    pub current: f64,
+}
+
+impl Regression {
+    pub fn percent_slower(&self) -> f64 {
+        (self.current / self.baseline - 1.0) * 100.0
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {:02} {}: {:.3} ms -> {:.3} ms (+{:.0}%)",
+            self.day,
+            self.phase,
+            self.baseline,
+            self.current,
+            self.percent_slower()
+        )
+    }
+}
+
+pub fn find_regressions(
+    baseline: &[Measurement],
+    current: &[Measurement],
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    for measurement in current {
+        let Some(old) = baseline.iter().find(|old| old.day == measurement.day) else {
+            continue;
+        };
+        for ((phase, current), (_, old)) in measurement.phases().into_iter().zip(old.phases()) {
+            if let (Some(current), Some(baseline)) = (current, old) {
+                let regression = Regression {
+                    day: measurement.day,
+                    phase,
+                    baseline,
+                    current,
+                };
+                if baseline > 0.0 && regression.percent_slower() > threshold_percent {
+                    regressions.push(regression);
+                }
+            }
+        }
+    }
+    regressions
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(PathBuf, std::io::Error),
+    Json(PathBuf, serde_json::Error),
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineError::Io(path, error) => {
+                write!(f, "could not access {}: {error}", path.display())
+            }
+            BaselineError::Json(path, error) => {
+                write!(f, "invalid baseline in {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+/// Reads a saved baseline, which is empty when none was saved yet.
+pub fn load_baseline(path: &Path) -> Result<Vec<Measurement>, BaselineError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => {
+            serde_json::from_str(&text).map_err(|error| BaselineError::Json(path.into(), error))
+        }
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(BaselineError::Io(path.into(), error)),
+    }
+}
+
+/// Stores the measurements as the new baseline, keeping the days that were not measured.
+pub fn save_baseline(path: &Path, measurements: &[Measurement]) -> Result<(), BaselineError> {
+    let mut baseline = load_baseline(path)?;
+    baseline.retain(|old| !measurements.iter().any(|new| new.day == old.day));
+    baseline.extend_from_slice(measurements);
+    baseline.sort_by_key(|measurement| measurement.day);
+
+    let text = serde_json::to_string_pretty(&baseline)
+        .map_err(|error| BaselineError::Json(path.into(), error))?;
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)
+            .map_err(|error| BaselineError::Io(path.into(), error))?;
+    }
+    std::fs::write(path, text).map_err(|error| BaselineError::Io(path.into(), error))
+}
+
+#[test]
+fn test_find_regressions() {
+    let measurement = |day, parse, part1| Measurement {
+        day,
+        iterations: 5,
+        parse,
+        part1: Some(part1),
+        part2: None,
+    };
+    let baseline = [measurement(1, 1.0, 10.0), measurement(2, 1.0, 10.0)];
+    let current = [measurement(1, 1.05, 20.0), measurement(3, 5.0, 5.0)];
+    let regressions = find_regressions(&baseline, &current, 10.0);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!((regressions[0].day, regressions[0].phase), (1, "part 1"));
+    assert_eq!(regressions[0].percent_slower(), 100.0);
+}
@@ -0,0 +1,95 @@
+use crate::grid::Grid;
+use crate::vector2::Vector2i;
+use std::collections::VecDeque;
+
+/// Which cells count as adjacent when walking over a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Up, down, left and right.
+    Four,
+    /// The four sides and the four corners.
+    Eight,
+}
+
+impl Neighbourhood {
+    pub fn directions(self) -> impl Iterator<Item = Vector2i> {
+        let diagonals = match self {
+            Neighbourhood::Four => &[][..],
+            Neighbourhood::Eight => &Vector2i::DIAGONAL_VECTORS[..],
+        };
+        Vector2i::DIRECTION_VECTORS
+            .into_iter()
+            .chain(diagonals.iter().copied())
+    }
+}
+
+impl<T> Grid<T> {
+    /// The number of orthogonal steps from the nearest source to every cell, moving only through
+    /// cells that are `passable`. Unreachable cells are `None`.
+    pub fn distances(
+        &self,
+        sources: impl IntoIterator<Item = Vector2i>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.distances_with(sources, passable, Neighbourhood::Four, false)
+    }
+
+    /// Like `distances`, with a choice of neighbourhood and whether walking off one edge of the
+    /// grid comes back in on the opposite side.
+    pub fn distances_with(
+        &self,
+        sources: impl IntoIterator<Item = Vector2i>,
+        passable: impl Fn(&T) -> bool,
+        neighbourhood: Neighbourhood,
+        wrap: bool,
+    ) -> Grid<Option<u32>> {
+        let mut distances = Grid::empty(self.size, None);
+        let mut queue = VecDeque::new();
+        for source in sources {
+            if self.contains(&source) && passable(self.get(&source)) {
+                distances.set(&source, Some(0));
+                queue.push_back(source);
+            }
+        }
+
+        while let Some(position) = queue.pop_front() {
+            let next_distance = distances.get(&position).map(|distance| distance + 1);
+            for direction in neighbourhood.directions() {
+                let mut next = position + direction;
+                if wrap {
+                    next = next.modulo(self.size);
+                }
+                if !self.contains(&next) || distances.get(&next).is_some() {
+                    continue;
+                }
+                if passable(self.get(&next)) {
+                    distances.set(&next, next_distance);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[test]
+fn test_distances() {
+    let (grid, markers) = Grid::from_text("S.#.\n.##.\n...E", '#', "SE", Some).unwrap();
+    let open = |c: &char| *c != '#';
+    let start = markers.single('S').unwrap();
+    let end = markers.single('E').unwrap();
+
+    let distances = grid.distances([start], open);
+    assert_eq!(*distances.get(&end), Some(5));
+    assert_eq!(*distances.get(&Vector2i::new(3, 0)), Some(7));
+    assert_eq!(*distances.get(&Vector2i::new(2, 0)), None);
+
+    let distances = grid.distances([start, end], open);
+    assert_eq!(*distances.get(&Vector2i::new(3, 0)), Some(2));
+
+    let distances = grid.distances_with([start], open, Neighbourhood::Eight, false);
+    assert_eq!(*distances.get(&end), Some(4));
+
+    let distances = grid.distances_with([start], open, Neighbourhood::Four, true);
+    assert_eq!(*distances.get(&end), Some(2));
+}
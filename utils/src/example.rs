@@ -0,0 +1,87 @@
+use crate::{Answer, Solution};
+
+/// A worked example from the puzzle text, with the answers the puzzle gives for it.
+#[derive(Debug, Clone, Copy)]
+pub struct Example<P: 'static = ()> {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    /// The puzzle settings the example is solved with, see `Solution::Params`.
+    pub params: P,
+}
+
+impl Example {
+    pub const fn new(input: &'static str) -> Example {
+        Example::with_params(input, ())
+    }
+}
+
+impl<P: Copy> Example<P> {
+    pub const fn with_params(input: &'static str, params: P) -> Example<P> {
+        Example {
+            input,
+            part1: None,
+            part2: None,
+            params,
+        }
+    }
+
+    pub const fn part1(self, answer: &'static str) -> Example<P> {
+        Example {
+            part1: Some(answer),
+            ..self
+        }
+    }
+
+    pub const fn part2(self, answer: &'static str) -> Example<P> {
+        Example {
+            part2: Some(answer),
+            ..self
+        }
+    }
+}
+
+/// Parses every example of `S` and checks the answers it declares.
+pub fn check_examples<S: Solution>() {
+    for (index, example) in S::EXAMPLES.iter().enumerate() {
+        let input = S::parse_with(example.input, &example.params).unwrap_or_else(|error| {
+            let error = error.locate(example.input);
+            panic!("day {} example {}: {}", S::DAY, index + 1, error.snippet())
+        });
+        if let Some(expected) = example.part1 {
+            let answer = S::part1(&input).into_answer().unwrap_or_else(|reason| {
+                panic!("day {} example {} part 1: {reason}", S::DAY, index + 1)
+            });
+            assert_eq!(
+                answer,
+                expected,
+                "day {} example {} part 1",
+                S::DAY,
+                index + 1
+            );
+        }
+        if let Some(expected) = example.part2 {
+            let answer = S::part2(&input).into_answer().unwrap_or_else(|reason| {
+                panic!("day {} example {} part 2: {reason}", S::DAY, index + 1)
+            });
+            assert_eq!(
+                answer,
+                expected,
+                "day {} example {} part 2",
+                S::DAY,
+                index + 1
+            );
+        }
+    }
+}
+
+/// Adds a test that checks every `EXAMPLES` entry of the given solution.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples() {
+            $crate::example::check_examples::<$solution>();
+        }
+    };
+}
@@ -0,0 +1,220 @@
+use crate::grid::Grid;
+use crate::search::{self, SearchProblem, ShortestPaths};
+use crate::vector2::Vector2i;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// An adjacency list graph searched towards an optional target.
+struct GraphSearch<'a, T> {
+    graph: &'a HashMap<T, Vec<(usize, T)>>,
+    target: Option<T>,
+}
+
+impl<T: Eq + Hash + Copy> SearchProblem for GraphSearch<'_, T> {
+    type State = T;
+
+    fn neighbors(&self, node: &T) -> impl Iterator<Item = (usize, T)> {
+        self.graph.get(node).into_iter().flatten().copied()
+    }
+
+    fn is_goal(&self, node: &T) -> bool {
+        Some(*node) == self.target
+    }
+}
+
+/// Shortest paths from `start` over a graph of `(cost, neighbour)` edge lists.
+///
+/// With a `target` the search stops once every node as far away as the target is settled, and
+/// only those settled nodes are kept, so farther nodes come back as unreached.
+pub fn dijkstra<T>(
+    graph: &HashMap<T, Vec<(usize, T)>>,
+    start: T,
+    target: Option<T>,
+) -> ShortestPaths<T>
+where
+    T: Eq + Hash + Copy,
+{
+    search::dijkstra(&GraphSearch { graph, target }, start)
+}
+
+/// Collects the edges between the cells of a grid, see `Grid::graph`.
+pub struct GraphBuilder<'a, T> {
+    grid: &'a Grid<T>,
+    passable: Box<dyn Fn(&T) -> bool + 'a>,
+    cost: Box<dyn Fn(Vector2i, Vector2i) -> usize + 'a>,
+    /// Extra `(destination, cost)` edges, by the cell they leave from.
+    portals: HashMap<Vector2i, Vec<(Vector2i, usize)>>,
+}
+
+impl<T> Grid<T> {
+    /// Starts building a graph with an edge of cost 1 between orthogonally adjacent cells that
+    /// are both `passable`.
+    pub fn graph<'a>(&'a self, passable: impl Fn(&T) -> bool + 'a) -> GraphBuilder<'a, T> {
+        GraphBuilder {
+            grid: self,
+            passable: Box::new(passable),
+            cost: Box::new(|_, _| 1),
+            portals: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, T> GraphBuilder<'a, T> {
+    /// Sets the cost of stepping from one cell to an adjacent one.
+    pub fn cost(mut self, cost: impl Fn(Vector2i, Vector2i) -> usize + 'a) -> Self {
+        self.cost = Box::new(cost);
+        self
+    }
+
+    /// Adds a one-way edge between any two cells, like a teleporter or a cheat.
+    pub fn portal(mut self, from: Vector2i, to: Vector2i, cost: usize) -> Self {
+        self.portals.entry(from).or_default().push((to, cost));
+        self
+    }
+
+    pub fn build(self) -> GridGraph {
+        let size = self.grid.size;
+        let mut graph = GridGraph {
+            size,
+            offsets: Vec::with_capacity((size.x * size.y) as usize + 1),
+            edges: Vec::new(),
+        };
+        let is_open = |position: &Vector2i| {
+            self.grid.contains(position) && (self.passable)(self.grid.get(position))
+        };
+
+        graph.offsets.push(0);
+        for position in self.grid.coordinates() {
+            if is_open(&position) {
+                for direction in Vector2i::DIRECTION_VECTORS {
+                    let next = position + direction;
+                    if is_open(&next) {
+                        let index = graph.index(&next).unwrap();
+                        graph.edges.push(((self.cost)(position, next), index));
+                    }
+                }
+                for (to, cost) in self.portals.get(&position).into_iter().flatten() {
+                    if let Some(index) = graph.index(to) {
+                        graph.edges.push((*cost, index));
+                    }
+                }
+            }
+            graph.offsets.push(graph.edges.len());
+        }
+        graph
+    }
+}
+
+/// A graph over the cells of a grid, numbered in reading order, with the edges of each cell
+/// stored next to each other.
+#[derive(Debug, Clone)]
+pub struct GridGraph {
+    size: Vector2i,
+    offsets: Vec<usize>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl GridGraph {
+    /// The number of a cell, or `None` outside the grid.
+    pub fn index(&self, position: &Vector2i) -> Option<usize> {
+        let inside =
+            (0..self.size.x).contains(&position.x) && (0..self.size.y).contains(&position.y);
+        inside.then(|| (position.y * self.size.x + position.x) as usize)
+    }
+
+    pub fn position(&self, index: usize) -> Vector2i {
+        let index = index as i64;
+        Vector2i::new(index % self.size.x, index / self.size.x)
+    }
+
+    /// The `(cost, neighbour)` edges leaving a cell.
+    pub fn edges(&self, index: usize) -> &[(usize, usize)] {
+        &self.edges[self.offsets[index]..self.offsets[index + 1]]
+    }
+
+    /// Shortest paths from `start`, stopping early once `target` is settled.
+    ///
+    /// The search runs over cell numbers, and only the result is converted back to positions.
+    pub fn shortest_paths(
+        &self,
+        start: Vector2i,
+        target: Option<Vector2i>,
+    ) -> ShortestPaths<Vector2i> {
+        let Some(start_index) = self.index(&start) else {
+            return ShortestPaths::only_start(start);
+        };
+        let problem = GridGraphSearch {
+            graph: self,
+            target: target.and_then(|target| self.index(&target)),
+        };
+        search::dijkstra(&problem, start_index).map(|index| self.position(index))
+    }
+}
+
+struct GridGraphSearch<'a> {
+    graph: &'a GridGraph,
+    target: Option<usize>,
+}
+
+impl SearchProblem for GridGraphSearch<'_> {
+    type State = usize;
+
+    fn neighbors(&self, index: &usize) -> impl Iterator<Item = (usize, usize)> {
+        self.graph.edges(*index).iter().copied()
+    }
+
+    fn is_goal(&self, index: &usize) -> bool {
+        Some(*index) == self.target
+    }
+}
+
+#[test]
+fn test_dijkstra() {
+    let graph = HashMap::from([
+        ('a', vec![(1, 'b'), (4, 'c')]),
+        ('b', vec![(3, 'c'), (5, 'd')]),
+        ('c', vec![(2, 'd')]),
+        ('e', vec![(1, 'a')]),
+    ]);
+    let paths = dijkstra(&graph, 'a', None);
+    assert_eq!(paths.distance(&'c'), Some(4));
+    assert_eq!(paths.predecessors(&'c'), &['a', 'b']);
+    assert_eq!(paths.distance(&'d'), Some(6));
+    assert_eq!(paths.distance(&'e'), None);
+    assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'b', 'd']));
+    assert_eq!(paths.path_to(&'e'), None);
+
+    let paths = dijkstra(&graph, 'a', Some('b'));
+    assert_eq!(paths.path_to(&'b'), Some(vec!['a', 'b']));
+    // Both were still on the frontier when the search stopped.
+    assert_eq!(paths.distance(&'c'), None);
+    assert_eq!(paths.distance(&'d'), None);
+}
+
+#[test]
+fn test_grid_graph() {
+    let (grid, markers) = Grid::from_text("S.#.\n.##E", '#', "SE", Some).unwrap();
+    let start = markers.single('S').unwrap();
+    let end = markers.single('E').unwrap();
+
+    let graph = grid.graph(|c| *c != '#').build();
+    assert_eq!(graph.edges(graph.index(&start).unwrap()).len(), 2);
+    assert_eq!(graph.shortest_paths(start, Some(end)).distance(&end), None);
+
+    let graph = grid
+        .graph(|c| *c != '#')
+        .cost(|_, to| if to.y == 0 { 2 } else { 1 })
+        .portal(Vector2i::new(1, 0), Vector2i::new(3, 0), 5)
+        .build();
+    let paths = graph.shortest_paths(start, Some(end));
+    assert_eq!(paths.distance(&end), Some(8));
+    assert_eq!(
+        paths.path_to(&end),
+        Some(vec![start, Vector2i::new(1, 0), Vector2i::new(3, 0), end])
+    );
+
+    let outside = Vector2i::new(-1, 0);
+    let paths = graph.shortest_paths(outside, Some(end));
+    assert_eq!(paths.distance(&outside), Some(0));
+    assert_eq!(paths.distance(&end), None);
+}
@@ -0,0 +1,164 @@
+use crate::parse::{self, ParseError};
+use crate::vector2::Vector2i;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+    pub default: T,
+    pub size: Vector2i,
+    pub values: Vec<Vec<T>>,
+}
+
+impl<T> Grid<T> {
+    pub fn contains(&self, location: &Vector2i) -> bool {
+        location.x >= 0 && location.x < self.size.x && location.y >= 0 && location.y < self.size.y
+    }
+
+    pub fn get(&self, location: &Vector2i) -> &T {
+        if !self.contains(location) {
+            return &self.default;
+        }
+        &self.values[location.x as usize][location.y as usize]
+    }
+
+    pub fn get_mut(&mut self, location: &Vector2i) -> &mut T {
+        if !self.contains(location) {
+            return &mut self.default;
+        }
+        &mut self.values[location.x as usize][location.y as usize]
+    }
+
+    pub fn set(&mut self, location: &Vector2i, value: T) -> bool {
+        if !self.contains(location) {
+            return false;
+        }
+        self.values[location.x as usize][location.y as usize] = value;
+        true
+    }
+
+    pub fn coordinates(&self) -> Vec<Vector2i> {
+        (0..self.size.y)
+            .flat_map(|y| (0..self.size.x).map(move |x| Vector2i { x, y }))
+            .collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn empty(size: Vector2i, default: T) -> Grid<T> {
+        let values = (0..size.x)
+            .map(|_| (0..size.y).map(|_| default.clone()).collect())
+            .collect();
+
+        Grid::<T> {
+            default,
+            size,
+            values,
+        }
+    }
+
+    /// Parses a grid of characters, one row per line, sized by the text itself.
+    ///
+    /// `cell` turns each character into a value, returning `None` for characters that are not
+    /// allowed. The positions of the characters in `markers`, like `S` and `E`, are returned too.
+    pub fn from_text<'a>(
+        input: &'a str,
+        default: T,
+        markers: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<(Grid<T>, Markers<'a>), ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let height = input.lines().count();
+        let mut grid = Grid::empty(Vector2i::new(width as i64, height as i64), default);
+        let mut found = Markers {
+            input,
+            positions: HashMap::new(),
+        };
+
+        for (y, line) in input.lines().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(line, format!("a row {width} wide")));
+            }
+            for (x, (index, c)) in line.char_indices().enumerate() {
+                let Some(value) = cell(c) else {
+                    return Err(parse::unexpected_char(line, index, accepted(&cell)));
+                };
+                let position = Vector2i::new(x as i64, y as i64);
+                grid.set(&position, value);
+                if markers.contains(c) {
+                    found.positions.entry(c).or_default().push(position);
+                }
+            }
+        }
+        Ok((grid, found))
+    }
+}
+
+/// Describes which printable characters a cell mapper accepts, for error messages.
+fn accepted<T>(cell: &impl Fn(char) -> Option<T>) -> String {
+    let characters: String = (' '..='~').filter(|c| cell(*c).is_some()).collect();
+    format!("one of `{characters}`")
+}
+
+/// Where the marker characters of a text grid were found.
+#[derive(Debug, Clone)]
+pub struct Markers<'a> {
+    input: &'a str,
+    positions: HashMap<char, Vec<Vector2i>>,
+}
+
+impl Markers<'_> {
+    /// Every position of `marker`, in reading order.
+    pub fn all(&self, marker: char) -> &[Vector2i] {
+        self.positions.get(&marker).map_or(&[], Vec::as_slice)
+    }
+
+    /// The position of a marker that must appear exactly once, like a start tile.
+    pub fn single(&self, marker: char) -> Result<Vector2i, ParseError> {
+        match self.all(marker) {
+            [position] => Ok(*position),
+            [] => Err(ParseError::new(
+                &self.input[self.input.len()..],
+                format!("a `{marker}` tile"),
+            )),
+            [_, second, ..] => {
+                let line = self.input.lines().nth(second.y as usize).unwrap_or("");
+                let index = line
+                    .char_indices()
+                    .nth(second.x as usize)
+                    .map_or(0, |(index, _)| index);
+                Err(parse::unexpected_char(
+                    line,
+                    index,
+                    format!("only one `{marker}` tile"),
+                ))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_from_text() {
+    let input = "#####\n#S.E#\n#####";
+    let (grid, markers) = Grid::from_text(input, false, "SE", |c| match c {
+        '#' => Some(false),
+        '.' | 'S' | 'E' => Some(true),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(grid.size, Vector2i::new(5, 3));
+    assert!(*grid.get(&Vector2i::new(2, 1)));
+    assert!(!*grid.get(&Vector2i::new(4, 1)));
+    assert_eq!(markers.single('S').unwrap(), Vector2i::new(1, 1));
+    assert_eq!(markers.single('E').unwrap(), Vector2i::new(3, 1));
+    assert!(markers.single('@').is_err());
+
+    let error = Grid::from_text("#.\n#x", false, "", |c| match c {
+        '#' => Some(false),
+        '.' => Some(true),
+        _ => None,
+    })
+    .unwrap_err();
+    assert_eq!(error.expected, "one of `#.`");
+    assert_eq!(error.text, "x");
+    assert!(Grid::from_text("##\n#", false, "", |_| Some(true)).is_err());
+}
@@ -0,0 +1,101 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_INPUTS_DIRECTORY: &str = "inputs";
+
+/// Where a day's puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<inputs directory>/<year>/dayNN.txt`
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets an `--input` argument, where `-` means stdin.
+    pub fn from_argument(argument: Option<&Path>) -> InputSource {
+        match argument {
+            None => InputSource::Default,
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: Option<PathBuf>,
+    pub error: std::io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "could not read {}: {}", path.display(), self.error),
+            None => write!(f, "could not read stdin: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[derive(Debug, Clone)]
+pub struct InputLoader {
+    pub inputs_directory: PathBuf,
+}
+
+impl Default for InputLoader {
+    fn default() -> Self {
+        InputLoader::new(DEFAULT_INPUTS_DIRECTORY)
+    }
+}
+
+impl InputLoader {
+    pub fn new(inputs_directory: impl Into<PathBuf>) -> InputLoader {
+        InputLoader {
+            inputs_directory: inputs_directory.into(),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.inputs_directory
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    pub fn load(&self, year: u16, day: u8, source: &InputSource) -> Result<String, InputError> {
+        let path = match source {
+            InputSource::Default => Some(self.path(year, day)),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        };
+        let read = match &path {
+            Some(path) => std::fs::read_to_string(path),
+            None => {
+                let mut buffer = String::new();
+                std::io::stdin().read_to_string(&mut buffer).map(|_| buffer)
+            }
+        };
+        read.map(|raw| normalize(&raw))
+            .map_err(|error| InputError { path, error })
+    }
+}
+
+/// Turns CRLF into LF and strips trailing whitespace from every line and from the end of the input.
+pub fn normalize(raw: &str) -> String {
+    raw.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize("1 2 \r\n3 4\r\n\r\n"), "1 2\n3 4");
+    assert_eq!(normalize("a\n\nb  \n"), "a\n\nb");
+    assert_eq!(normalize(""), "");
+}
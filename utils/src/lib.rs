@@ -0,0 +1,24 @@
+pub mod answers;
+pub mod bench;
+pub mod distances;
+pub mod example;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod vector2;
+
+pub use answers::{AnswerStore, Answers, Verdict};
+pub use distances::Neighbourhood;
+pub use example::Example;
+pub use graph::{GridGraph, dijkstra};
+pub use grid::{Grid, Markers};
+pub use input::{InputLoader, InputSource};
+pub use parse::ParseError;
+pub use render::Rendering;
+pub use search::{OptimalPaths, SearchProblem, ShortestPaths};
+pub use solution::{Answer, Part, PhaseTimes, Runnable, Solution, SolveError};
+pub use vector2::Vector2i;
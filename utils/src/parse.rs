@@ -0,0 +1,256 @@
+use std::fmt::{Debug, Display};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Why a puzzle input could not be parsed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The offending part of the input.
+    pub text: String,
+    /// What should have been there instead, e.g. "a number".
+    pub expected: String,
+    /// Filled in by `locate` once the whole input is known.
+    pub location: Option<Location>,
+    address: usize,
+}
+
+/// A 1-based position in the input, with the line it is on for error snippets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// `text` should be a slice of the input, so `locate` can later tell where it came from.
+    pub fn new(text: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            text: text.to_string(),
+            expected: expected.into(),
+            location: None,
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// Works out the line and column of the offending text within `input`.
+    pub fn locate(mut self, input: &str) -> ParseError {
+        if self.location.is_some() {
+            return self;
+        }
+        let start = input.as_ptr() as usize;
+        let Some(offset) = self.address.checked_sub(start) else {
+            return self;
+        };
+        if offset > input.len() || !input.is_char_boundary(offset) {
+            return self;
+        }
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source_line: input[line_start..line_end].to_string(),
+        });
+        self
+    }
+
+    /// The error message followed by the offending line with the text underlined.
+    pub fn snippet(&self) -> String {
+        let Some(location) = &self.location else {
+            return self.to_string();
+        };
+        let number = location.line.to_string();
+        let padding = " ".repeat(number.len());
+        let underline = self.text.lines().next().unwrap_or("").chars().count();
+        format!(
+            "{self}\n{padding} |\n{number} | {}\n{padding} | {}{}",
+            location.source_line,
+            " ".repeat(location.column - 1),
+            "^".repeat(underline.max(1))
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "line {}, column {}: ", location.line, location.column)?;
+        }
+        if self.text.is_empty() {
+            write!(f, "expected {}, found nothing", self.expected)
+        } else {
+            write!(f, "expected {}, found `{}`", self.expected, self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::new(text, "a number"))
+}
+
+/// A number that must lie in `range`, for solutions that index arrays with it.
+pub fn number_in<T: FromStr + PartialOrd + Debug>(
+    text: &str,
+    range: Range<T>,
+) -> Result<T, ParseError> {
+    let value = number(text)?;
+    if !range.contains(&value) {
+        return Err(ParseError::new(text, format!("a number in {range:?}")));
+    }
+    Ok(value)
+}
+
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("`{delimiter}` somewhere")))
+}
+
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(text, format!("text starting with `{prefix}`")))
+}
+
+/// An error for a single unexpected character of `line`, which starts at byte `index`.
+pub fn unexpected_char(line: &str, index: usize, expected: impl Into<String>) -> ParseError {
+    let length = line[index..].chars().next().map_or(0, char::len_utf8);
+    ParseError::new(&line[index..index + length], expected)
+}
+
+/// Every signed integer in `text`, ignoring whatever is around them, as in `p=0,4 v=3,-3`.
+pub fn integers(text: &str) -> Result<Vec<i64>, ParseError> {
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let negative = bytes[index] == b'-' && bytes.get(index + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        numbers.push(number(&text[start..index])?);
+    }
+    Ok(numbers)
+}
+
+/// Exactly `N` signed integers from `text`, for lines with a fixed shape.
+pub fn fixed_integers<const N: usize>(text: &str) -> Result<[i64; N], ParseError> {
+    integers(text)?
+        .try_into()
+        .map_err(|_| ParseError::new(text, format!("{N} numbers")))
+}
+
+/// Blocks of lines separated by blank lines.
+pub fn sections(input: &str) -> Vec<&str> {
+    input.split("\n\n").collect()
+}
+
+/// The two blank-line separated sections of inputs like `rules\n\nupdates`.
+pub fn section_pair(input: &str) -> Result<(&str, &str), ParseError> {
+    input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(input, "two sections separated by a blank line"))
+}
+
+/// Comma separated values, allowing spaces around the commas.
+pub fn comma_list<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split(',').map(|item| number(item.trim())).collect()
+}
+
+/// Values separated by any amount of whitespace.
+pub fn whitespace_list<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(number).collect()
+}
+
+/// A block of `key: value` lines, like the registers and program of day 17.
+#[derive(Debug, Clone)]
+pub struct KeyValues<'a> {
+    block: &'a str,
+    pairs: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> KeyValues<'a> {
+    /// Reads every non-blank line of `block` as `key: value`.
+    pub fn parse(block: &'a str) -> Result<KeyValues<'a>, ParseError> {
+        let pairs = block
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_once(':')
+                    .map(|(key, value)| (key.trim(), value.trim()))
+                    .ok_or_else(|| ParseError::new(line, "a `key: value` line"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(KeyValues { block, pairs })
+    }
+
+    pub fn get(&self, key: &str) -> Result<&'a str, ParseError> {
+        self.pairs
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| {
+                ParseError::new(&self.block[self.block.len()..], format!("a `{key}:` line"))
+            })
+    }
+
+    pub fn number<T: FromStr>(&self, key: &str) -> Result<T, ParseError> {
+        number(self.get(key)?)
+    }
+
+    pub fn comma_list<T: FromStr>(&self, key: &str) -> Result<Vec<T>, ParseError> {
+        comma_list(self.get(key)?)
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "47|53\n97|1x\n";
+    let line = input.lines().nth(1).unwrap();
+    let error = number::<usize>(&line[3..]).unwrap_err().locate(input);
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 4: expected a number, found `1x`"
+    );
+    assert_eq!(
+        error.snippet(),
+        "line 2, column 4: expected a number, found `1x`\n  |\n2 | 97|1x\n  |    ^^"
+    );
+
+    let elsewhere = String::from("1x");
+    let error = number::<usize>(&elsewhere).unwrap_err().locate(input);
+    assert_eq!(error.location, None);
+}
+
+#[test]
+fn test_helpers() {
+    assert_eq!(integers("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+    assert_eq!(
+        fixed_integers::<2>("Button A: X+94, Y+34").unwrap(),
+        [94, 34]
+    );
+    assert!(fixed_integers::<3>("1,2").is_err());
+    assert_eq!(sections("a\nb\n\nc"), vec!["a\nb", "c"]);
+    assert_eq!(section_pair("a\n\nb\n\nc").unwrap(), ("a", "b\n\nc"));
+    assert_eq!(comma_list::<u8>("0, 1,5").unwrap(), vec![0, 1, 5]);
+    assert_eq!(whitespace_list::<i32>("3   4 -1").unwrap(), vec![3, 4, -1]);
+    assert_eq!(number_in("99", 0..100), Ok(99));
+    assert_eq!(
+        number_in("100", 0..100).unwrap_err().expected,
+        "a number in 0..100"
+    );
+
+    let block = KeyValues::parse("Register A: 729\n\nProgram: 0,1").unwrap();
+    assert_eq!(block.number::<i64>("Register A").unwrap(), 729);
+    assert_eq!(block.comma_list::<u8>("Program").unwrap(), vec![0, 1]);
+    assert!(block.get("Register B").is_err());
+}
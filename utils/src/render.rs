@@ -0,0 +1,109 @@
+use crate::grid::Grid;
+use crate::vector2::Vector2i;
+use std::fmt::Display;
+
+/// A grid drawn as text, with overlays on top of the cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rendering {
+    rows: Vec<Vec<char>>,
+}
+
+impl<T> Grid<T> {
+    /// Draws every cell with `glyph`, one line per row.
+    pub fn render(&self, glyph: impl Fn(&T) -> char) -> Rendering {
+        let rows = (0..self.size.y)
+            .map(|y| {
+                (0..self.size.x)
+                    .map(|x| glyph(self.get(&Vector2i::new(x, y))))
+                    .collect()
+            })
+            .collect();
+        Rendering { rows }
+    }
+}
+
+impl Rendering {
+    /// Draws `glyph` on each of the positions, ignoring those outside the grid.
+    pub fn mark<'a>(
+        mut self,
+        positions: impl IntoIterator<Item = &'a Vector2i>,
+        glyph: char,
+    ) -> Self {
+        for position in positions {
+            self.draw(*position, glyph);
+        }
+        self
+    }
+
+    /// Draws a single entity, like the robot, on top of everything drawn so far.
+    pub fn entity(mut self, position: Vector2i, glyph: char) -> Self {
+        self.draw(position, glyph);
+        self
+    }
+
+    /// Draws a path as arrows pointing at the next step; the last position is left as it is.
+    pub fn path(mut self, path: &[Vector2i]) -> Self {
+        for step in path.windows(2) {
+            let direction = step[1] - step[0];
+            let arrow = match (direction.x.signum(), direction.y.signum()) {
+                (1, 0) => '>',
+                (-1, 0) => '<',
+                (0, 1) => 'v',
+                (0, -1) => '^',
+                _ => '*',
+            };
+            self.draw(step[0], arrow);
+        }
+        self
+    }
+
+    fn draw(&mut self, position: Vector2i, glyph: char) {
+        if position.x < 0 || position.y < 0 {
+            return;
+        }
+        if let Some(cell) = self
+            .rows
+            .get_mut(position.y as usize)
+            .and_then(|row| row.get_mut(position.x as usize))
+        {
+            *cell = glyph;
+        }
+    }
+}
+
+impl Display for Rendering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows.iter().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(|c| *c).fmt(f)
+    }
+}
+
+#[test]
+fn test_render() {
+    let (grid, markers) = Grid::from_text("#####\n#S..#\n#.#E#\n#####", '#', "SE", Some).unwrap();
+    assert_eq!(grid.to_string(), "#####\n#S..#\n#.#E#\n#####");
+
+    let path = [
+        markers.single('S').unwrap(),
+        Vector2i::new(2, 1),
+        Vector2i::new(3, 1),
+        markers.single('E').unwrap(),
+    ];
+    let rendering = grid
+        .render(|c| if *c == '#' { '#' } else { '.' })
+        .path(&path)
+        .mark(&[Vector2i::new(1, 2), Vector2i::new(9, 9)], 'O')
+        .entity(Vector2i::new(3, 2), '@');
+    assert_eq!(rendering.to_string(), "#####\n#>>v#\n#O#@#\n#####");
+}
@@ -0,0 +1,440 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A search space that is explored on demand instead of being built up front.
+pub trait SearchProblem {
+    type State: Eq + Hash + Copy;
+
+    /// Every state reachable from `state` in one move, with the cost of that move.
+    fn neighbors(&self, state: &Self::State) -> impl Iterator<Item = (usize, Self::State)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to a goal, used by `astar`.
+    ///
+    /// It must never overestimate and must be consistent, or `astar` can miss the best path.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+/// The result of a search: the distance to every reached state and all of its optimal predecessors.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<T> {
+    pub start: T,
+    /// The first goal that was reached, if any.
+    pub goal: Option<T>,
+    reached: HashMap<T, (usize, Vec<T>)>,
+}
+
+impl<T: Eq + Hash + Copy> ShortestPaths<T> {
+    /// A search that never left `start`.
+    pub(crate) fn only_start(start: T) -> ShortestPaths<T> {
+        ShortestPaths {
+            start,
+            goal: None,
+            reached: HashMap::from([(start, (0, Vec::new()))]),
+        }
+    }
+
+    /// The same paths with every node converted, like graph indices back to positions.
+    pub(crate) fn map<U: Eq + Hash>(self, convert: impl Fn(T) -> U) -> ShortestPaths<U> {
+        ShortestPaths {
+            start: convert(self.start),
+            goal: self.goal.map(&convert),
+            reached: self
+                .reached
+                .into_iter()
+                .map(|(node, (distance, predecessors))| {
+                    let predecessors = predecessors.into_iter().map(&convert).collect();
+                    (convert(node), (distance, predecessors))
+                })
+                .collect(),
+        }
+    }
+
+    /// The length of the shortest path to `node`, or `None` when it can't be reached.
+    pub fn distance(&self, node: &T) -> Option<usize> {
+        self.reached.get(node).map(|(distance, _)| *distance)
+    }
+
+    /// Every node that comes right before `node` on some shortest path.
+    pub fn predecessors(&self, node: &T) -> &[T] {
+        self.reached
+            .get(node)
+            .map_or(&[], |(_, predecessors)| predecessors.as_slice())
+    }
+
+    /// All reached nodes with their distances, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.reached
+            .iter()
+            .map(|(node, (distance, _))| (node, *distance))
+    }
+
+    /// The distance to the goal that ended the search.
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.distance(&self.goal?)
+    }
+
+    /// One shortest path from the start to `target`, including both ends.
+    pub fn path_to(&self, target: &T) -> Option<Vec<T>> {
+        self.distance(target)?;
+        let mut path = vec![*target];
+        let mut node = *target;
+        while node != self.start {
+            node = *self.predecessors(&node).first()?;
+            path.push(node);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The DAG of every shortest path from the start to any of `targets`.
+    ///
+    /// Targets that weren't reached are ignored.
+    pub fn optimal_paths(&self, targets: impl IntoIterator<Item = T>) -> OptimalPaths<'_, T> {
+        let targets: Vec<T> = targets
+            .into_iter()
+            .filter(|target| self.reached.contains_key(target))
+            .collect();
+        let mut nodes: HashSet<T> = targets.iter().copied().collect();
+        let mut to_explore = targets.clone();
+        while let Some(node) = to_explore.pop() {
+            for predecessor in self.predecessors(&node) {
+                if nodes.insert(*predecessor) {
+                    to_explore.push(*predecessor);
+                }
+            }
+        }
+        OptimalPaths {
+            paths: self,
+            targets,
+            nodes,
+        }
+    }
+}
+
+/// All shortest paths to some targets, as the optimal predecessors leading back to the start.
+#[derive(Debug, Clone)]
+pub struct OptimalPaths<'a, T> {
+    paths: &'a ShortestPaths<T>,
+    targets: Vec<T>,
+    nodes: HashSet<T>,
+}
+
+impl<'a, T: Eq + Hash + Copy> OptimalPaths<'a, T> {
+    /// Every node that lies on at least one of the paths.
+    pub fn nodes(&self) -> &HashSet<T> {
+        &self.nodes
+    }
+
+    /// How many distinct paths there are, or `None` if that doesn't fit in a `u128`.
+    pub fn count(&self) -> Option<u128> {
+        let mut counts: HashMap<T, Option<u128>> = HashMap::new();
+        let mut stack: Vec<(T, bool)> =
+            self.targets.iter().map(|target| (*target, false)).collect();
+        while let Some((node, expanded)) = stack.pop() {
+            if node == self.paths.start {
+                counts.insert(node, Some(1));
+                continue;
+            }
+            let predecessors = self.paths.predecessors(&node);
+            if expanded {
+                let count = predecessors.iter().try_fold(0u128, |total, predecessor| {
+                    total.checked_add(counts.get(predecessor).copied().flatten()?)
+                });
+                counts.insert(node, count);
+            } else if let Entry::Vacant(entry) = counts.entry(node) {
+                // Placeholder so zero-cost cycles don't loop forever.
+                entry.insert(Some(0));
+                stack.push((node, true));
+                stack.extend(
+                    predecessors
+                        .iter()
+                        .filter(|predecessor| !counts.contains_key(predecessor))
+                        .map(|predecessor| (*predecessor, false)),
+                );
+            }
+        }
+        self.targets.iter().try_fold(0u128, |total, target| {
+            total.checked_add(counts.get(target).copied().flatten()?)
+        })
+    }
+
+    /// Every path from the start to one of the targets, generated one at a time.
+    pub fn paths(&self) -> PathIter<'a, T> {
+        PathIter {
+            paths: self.paths,
+            targets: self.targets.clone(),
+            stack: Vec::new(),
+        }
+    }
+}
+
+/// Walks the predecessor DAG depth first, yielding a path whenever it reaches the start.
+#[derive(Debug, Clone)]
+pub struct PathIter<'a, T> {
+    paths: &'a ShortestPaths<T>,
+    targets: Vec<T>,
+    /// The current path from a target back towards the start, with the next predecessor to try.
+    stack: Vec<(T, usize)>,
+}
+
+impl<T: Eq + Hash + Copy> Iterator for PathIter<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        loop {
+            let Some((node, next_index)) = self.stack.last_mut() else {
+                self.stack.push((self.targets.pop()?, 0));
+                continue;
+            };
+            if *node == self.paths.start {
+                if *next_index > 0 {
+                    self.stack.pop();
+                    continue;
+                }
+                *next_index = 1;
+                return Some(self.stack.iter().rev().map(|(node, _)| *node).collect());
+            }
+            match self.paths.predecessors(node).get(*next_index) {
+                Some(predecessor) => {
+                    *next_index += 1;
+                    let predecessor = *predecessor;
+                    self.stack.push((predecessor, 0));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Breadth-first search, counting every move as a single step whatever its cost.
+///
+/// Stops at the first goal and only reports the states up to its depth, which are all final.
+pub fn bfs<P: SearchProblem>(problem: &P, start: P::State) -> ShortestPaths<P::State> {
+    let mut reached = HashMap::from([(start, (0, Vec::new()))]);
+    let mut queue = VecDeque::from([start]);
+    let mut goal = None;
+
+    while let Some(state) = queue.pop_front() {
+        if problem.is_goal(&state) {
+            goal = Some(state);
+            break;
+        }
+        let next_distance = reached[&state].0 + 1;
+        for (_, next) in problem.neighbors(&state) {
+            match reached.get_mut(&next) {
+                Some((distance, predecessors)) => {
+                    if *distance == next_distance {
+                        predecessors.push(state);
+                    }
+                }
+                None => {
+                    reached.insert(next, (next_distance, vec![state]));
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    // States one step deeper than the goal may still be missing predecessors.
+    if let Some(goal) = goal {
+        let goal_distance = reached[&goal].0;
+        reached.retain(|_, (distance, _)| *distance <= goal_distance);
+    }
+    ShortestPaths {
+        start,
+        goal,
+        reached,
+    }
+}
+
+/// Dijkstra's algorithm over the move costs.
+///
+/// Once a goal is settled the search only finishes the states that are as close as it is. Only
+/// settled states are reported, so every distance is final and farther states count as
+/// unreached.
+pub fn dijkstra<P: SearchProblem>(problem: &P, start: P::State) -> ShortestPaths<P::State> {
+    best_first(problem, start, |_| 0)
+}
+
+/// A* search, like `dijkstra` but exploring the states that look closest to a goal first.
+pub fn astar<P: SearchProblem>(problem: &P, start: P::State) -> ShortestPaths<P::State> {
+    best_first(problem, start, |state| problem.heuristic(state))
+}
+
+fn best_first<P: SearchProblem>(
+    problem: &P,
+    start: P::State,
+    heuristic: impl Fn(&P::State) -> usize,
+) -> ShortestPaths<P::State> {
+    let mut reached = HashMap::from([(start, (0, Vec::new()))]);
+    let mut settled = HashSet::new();
+    let mut queue = BinaryHeap::from([Candidate {
+        priority: heuristic(&start),
+        distance: 0,
+        state: start,
+    }]);
+    let mut goal = None;
+
+    while let Some(Candidate {
+        priority,
+        distance,
+        state,
+    }) = queue.pop()
+    {
+        if goal.is_some_and(|goal| priority > reached[&goal].0) {
+            break;
+        }
+        if !settled.insert(state) {
+            continue;
+        }
+        if goal.is_none() && problem.is_goal(&state) {
+            goal = Some(state);
+        }
+
+        for (cost, next) in problem.neighbors(&state) {
+            let next_distance = distance + cost;
+            let entry = reached.entry(next).or_insert((usize::MAX, Vec::new()));
+            match next_distance.cmp(&entry.0) {
+                Ordering::Less => {
+                    *entry = (next_distance, vec![state]);
+                    queue.push(Candidate {
+                        priority: next_distance + heuristic(&next),
+                        distance: next_distance,
+                        state: next,
+                    });
+                }
+                Ordering::Equal => entry.1.push(state),
+                Ordering::Greater => {}
+            }
+        }
+    }
+    // The frontier left behind by stopping early only has tentative distances.
+    reached.retain(|state, _| settled.contains(state));
+    ShortestPaths {
+        start,
+        goal,
+        reached,
+    }
+}
+
+/// Heap entry ordered by lowest priority first.
+struct Candidate<T> {
+    priority: usize,
+    distance: usize,
+    state: T,
+}
+
+impl<T> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<T> Eq for Candidate<T> {}
+
+impl<T> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[test]
+fn test_search() {
+    struct Corridor {
+        length: i64,
+        goal: i64,
+    }
+
+    impl SearchProblem for Corridor {
+        type State = i64;
+
+        fn neighbors(&self, state: &i64) -> impl Iterator<Item = (usize, i64)> {
+            [(1, state - 1), (1, state + 1), (2, state + 2)]
+                .into_iter()
+                .filter(|(_, next)| (0..=self.length).contains(next))
+        }
+
+        fn is_goal(&self, state: &i64) -> bool {
+            *state == self.goal
+        }
+
+        fn heuristic(&self, state: &i64) -> usize {
+            (self.goal - state).unsigned_abs() as usize
+        }
+    }
+
+    let corridor = Corridor { length: 4, goal: 4 };
+
+    let paths = bfs(&corridor, 0);
+    assert_eq!(paths.goal, Some(4));
+    assert_eq!(paths.goal_distance(), Some(2));
+    assert_eq!(paths.path_to(&4), Some(vec![0, 2, 4]));
+
+    let paths = dijkstra(&corridor, 0);
+    assert_eq!(paths.goal_distance(), Some(4));
+    assert_eq!(paths.predecessors(&2), &[0, 1]);
+    assert_eq!(paths.path_to(&4), Some(vec![0, 2, 4]));
+
+    let paths = astar(&corridor, 0);
+    assert_eq!(paths.goal_distance(), Some(4));
+    assert_eq!(paths.distance(&-1), None);
+
+    // 3 and 4 are still on the frontier when the goal is settled.
+    let short_of_the_end = Corridor { length: 6, goal: 2 };
+    let paths = dijkstra(&short_of_the_end, 0);
+    assert_eq!(paths.goal_distance(), Some(2));
+    assert_eq!(paths.distance(&3), None);
+    assert_eq!(paths.distance(&4), None);
+    assert_eq!(paths.distances().count(), 3);
+    assert_eq!(bfs(&short_of_the_end, 0).distance(&3), None);
+
+    let unreachable = Corridor {
+        length: -1,
+        goal: -1,
+    };
+    assert_eq!(dijkstra(&unreachable, 0).goal, None);
+}
+
+#[test]
+fn test_optimal_paths() {
+    use crate::graph;
+
+    let graph = HashMap::from([
+        ('a', vec![(1, 'b'), (1, 'c'), (3, 'e')]),
+        ('b', vec![(1, 'd')]),
+        ('c', vec![(1, 'd')]),
+        ('d', vec![(1, 'e')]),
+    ]);
+    let paths = graph::dijkstra(&graph, 'a', None);
+
+    let optimal = paths.optimal_paths(['e']);
+    assert_eq!(optimal.count(), Some(3));
+    assert_eq!(optimal.nodes().len(), 5);
+    let mut all: Vec<Vec<char>> = optimal.paths().collect();
+    all.sort();
+    assert_eq!(
+        all,
+        vec![
+            vec!['a', 'b', 'd', 'e'],
+            vec!['a', 'c', 'd', 'e'],
+            vec!['a', 'e']
+        ]
+    );
+
+    let optimal = paths.optimal_paths(['b', 'c', 'z']);
+    assert_eq!(optimal.count(), Some(2));
+    assert_eq!(optimal.paths().count(), 2);
+}
@@ -0,0 +1,143 @@
+use crate::example::Example;
+use crate::parse::ParseError;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A single day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    const YEAR: u16 = 2024;
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// Settings the puzzle text changes for its examples, like the size of a map. The default is
+    /// what the real input uses.
+    type Params: Default + Copy + 'static;
+
+    /// Examples from the puzzle text, checked by `example_tests!`.
+    const EXAMPLES: &'static [Example<Self::Params>] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses an input meant for other settings than the real puzzle's. Only days that have
+    /// settings need to implement it.
+    fn parse_with(input: &str, _params: &Self::Params) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// What a part returns: an answer to show, or the reason it couldn't find one.
+pub trait Answer {
+    fn into_answer(self) -> Result<String, String>;
+}
+
+macro_rules! impl_answer {
+    ($($answer:ty),*) => {
+        $(impl Answer for $answer {
+            fn into_answer(self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_answer!(i32, i64, u32, u64, u128, usize, String);
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<String, String> {
+        self.map(|answer| answer.to_string())
+            .map_err(|error| error.to_string())
+    }
+}
+
+impl<T: Display> Answer for Option<T> {
+    fn into_answer(self) -> Result<String, String> {
+        self.map(|answer| answer.to_string())
+            .ok_or_else(|| "no answer found".to_string())
+    }
+}
+
+/// Why solving a part gave no answer.
+#[derive(Debug, Clone)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input parsed but the part found no answer, for the given reason.
+    NoAnswer(String),
+}
+
+/// Object safe view of a `Solution`, so the runner can keep every day in one list.
+pub trait Runnable {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn solve(&self, input: &str, part: Part) -> Result<String, SolveError>;
+    /// Solves the given parts once, timing the parse and each part separately.
+    fn time(&self, input: &str, parts: &[Part]) -> Result<PhaseTimes, ParseError>;
+}
+
+/// Time spent in each phase of one solve.
+#[derive(Debug, Clone)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+impl<S: Solution> Runnable for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str, part: Part) -> Result<String, SolveError> {
+        let parsed = S::parse(input).map_err(|error| SolveError::Parse(error.locate(input)))?;
+        let answer = match part {
+            Part::One => S::part1(&parsed).into_answer(),
+            Part::Two => S::part2(&parsed).into_answer(),
+        };
+        answer.map_err(SolveError::NoAnswer)
+    }
+
+    fn time(&self, input: &str, parts: &[Part]) -> Result<PhaseTimes, ParseError> {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(input).map_err(|error| error.locate(input))?);
+        let parse = start.elapsed();
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                match part {
+                    Part::One => drop(black_box(S::part1(&parsed))),
+                    Part::Two => drop(black_box(S::part2(&parsed))),
+                }
+                (*part, start.elapsed())
+            })
+            .collect();
+        Ok(PhaseTimes { parse, parts })
+    }
+}
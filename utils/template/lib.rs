@@ -0,0 +1,29 @@
+use aoc_utils::{Example, ParseError, Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = DAY_NUMBER;
+
+    type Input = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example::new(TEST_INPUT)];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        todo!()
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        todo!()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        todo!()
+    }
+}
+
+const TEST_INPUT: &str = "";
+
+aoc_utils::example_tests!(DayXX);